@@ -0,0 +1,212 @@
+//!
+
+use std::collections::BTreeSet;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assets::LaserKind;
+use crate::events::{EventListener, GameEvent};
+use crate::paths;
+
+/// The mystery ship score needed for the jackpot achievement, which is only scored by hitting the
+/// centre of the ship.
+const JACKPOT_SCORE: u32 = 200;
+
+/// The most shots that can be fired while clearing a wave for the sharpshooter achievement. A wave
+/// has 40 invaders, so this allows 5 misses.
+const SHARPSHOOTER_SHOTS: u32 = 45;
+
+/// The wave that must be reached for the survivor achievement.
+const SURVIVOR_WAVE: u32 = 10;
+
+/// Something notable done during a game, unlocked once and kept between runs.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    Flawless,
+    Jackpot,
+    Sharpshooter,
+    Survivor,
+}
+
+impl Achievement {
+    ///
+    pub const ALL: [Achievement; 4] = [
+        Achievement::Flawless,
+        Achievement::Jackpot,
+        Achievement::Sharpshooter,
+        Achievement::Survivor,
+    ];
+
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Flawless => "Flawless",
+            Self::Jackpot => "Jackpot",
+            Self::Sharpshooter => "Sharpshooter",
+            Self::Survivor => "Survivor",
+        }
+    }
+
+    ///
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Flawless => "Clear a wave without losing a life",
+            Self::Jackpot => "Hit the mystery ship for 200 points",
+            Self::Sharpshooter => "Clear a wave with no more than 45 shots",
+            Self::Survivor => "Survive to wave 10",
+        }
+    }
+}
+
+/// The achievements that have been unlocked, along with the progress towards those that depend on
+/// how the current wave is going.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievements {
+    unlocked: BTreeSet<Achievement>,
+    #[serde(skip)]
+    newly_unlocked: Vec<Achievement>,
+    #[serde(skip)]
+    wave_shots: u32,
+    #[serde(skip)]
+    wave_lives_lost: u32,
+}
+
+impl Achievements {
+    /// Load achievements from a file, falling back to none unlocked if it doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        paths::load_toml(path)
+    }
+
+    /// Write achievements to a file, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        paths::save_toml(path, self)
+    }
+
+    ///
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    /// Forget the progress made towards achievements during the current wave.
+    pub fn reset_wave(&mut self) {
+        self.wave_shots = 0;
+        self.wave_lives_lost = 0;
+    }
+
+    /// Take the achievements unlocked since this was last called.
+    pub fn take_unlocked(&mut self) -> Vec<Achievement> {
+        std::mem::take(&mut self.newly_unlocked)
+    }
+
+    fn unlock(&mut self, achievement: Achievement) {
+        if self.unlocked.insert(achievement) {
+            self.newly_unlocked.push(achievement);
+        }
+    }
+}
+
+impl EventListener for Achievements {
+    fn on_event(&mut self, event: &GameEvent) -> io::Result<()> {
+        match *event {
+            GameEvent::LaserFired {
+                kind: LaserKind::Cannon,
+            } => self.wave_shots += 1,
+            GameEvent::MysteryShipHit { score } if score >= JACKPOT_SCORE => {
+                self.unlock(Achievement::Jackpot)
+            }
+            GameEvent::LifeLost { .. } => self.wave_lives_lost += 1,
+            GameEvent::WaveCleared { wave, .. } => {
+                if self.wave_lives_lost == 0 {
+                    self.unlock(Achievement::Flawless);
+                }
+
+                if self.wave_shots <= SHARPSHOOTER_SHOTS {
+                    self.unlock(Achievement::Sharpshooter);
+                }
+
+                if wave + 1 >= SURVIVOR_WAVE {
+                    self.unlock(Achievement::Survivor);
+                }
+
+                self.reset_wave();
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::assets::{Laser, MysteryShip};
+    use crate::events::DeathCause;
+    use crate::points;
+
+    /// Hit a mystery ship with a laser, the given distance from the ship's left edge.
+    fn hit_mystery_ship(offset: f64) -> GameEvent {
+        let mut ship = MysteryShip::new_at(100.0, points::CANNON_LASER_INITIAL_Y);
+        let laser = Laser::new_cannon(100.0 + offset - points::CANNON_LASER_INITIAL_X_OFFSET);
+        let score = ship.collides_with(&laser).unwrap();
+
+        GameEvent::MysteryShipHit { score }
+    }
+
+    #[test]
+    fn test_wave_achievements() {
+        let mut achievements = Achievements::default();
+        let fired = GameEvent::LaserFired {
+            kind: LaserKind::Cannon,
+        };
+
+        for _ in 0..SHARPSHOOTER_SHOTS + 1 {
+            achievements.on_event(&fired).unwrap();
+        }
+
+        achievements
+            .on_event(&GameEvent::WaveCleared { wave: 1, bonus: 0 })
+            .unwrap();
+
+        assert_eq!(achievements.take_unlocked(), vec![Achievement::Flawless]);
+
+        achievements
+            .on_event(&GameEvent::LifeLost {
+                cause: DeathCause::Invaded,
+            })
+            .unwrap();
+        achievements
+            .on_event(&GameEvent::WaveCleared { wave: 9, bonus: 0 })
+            .unwrap();
+
+        assert_eq!(
+            achievements.take_unlocked(),
+            vec![Achievement::Sharpshooter, Achievement::Survivor]
+        );
+        assert!(!achievements.is_unlocked(Achievement::Jackpot));
+    }
+
+    #[test]
+    fn test_achievements_unlock_once() {
+        let mut achievements = Achievements::default();
+
+        achievements.on_event(&hit_mystery_ship(2.0)).unwrap();
+        assert!(achievements.take_unlocked().is_empty());
+
+        achievements.on_event(&hit_mystery_ship(8.0)).unwrap();
+        achievements.on_event(&hit_mystery_ship(8.0)).unwrap();
+        assert_eq!(achievements.take_unlocked(), vec![Achievement::Jackpot]);
+
+        let contents = toml::to_string(&achievements).unwrap();
+        assert_eq!(contents, "unlocked = [\"jackpot\"]\n");
+        assert_eq!(
+            toml::from_str::<Achievements>(&contents).unwrap(),
+            achievements
+        );
+    }
+}
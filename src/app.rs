@@ -1,112 +1,347 @@
 //!
 
-use rand::rngs::ThreadRng;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::assets::{Bunkers, Cannon, InvaderGrid, Laser, MysteryShip};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
-const INVADER_LASER_COUNTER_DEFAULT: u8 = 16;
-const ALIEN_COUNTER_DEFAULT: u8 = 5;
-const DEFAULT_LIVES: u8 = 3;
-const MAX_LEVEL: u8 = 6;
+use crate::achievements::{Achievement, Achievements};
+use crate::assets::{
+    BunkerShape, Bunkers, Cannon, Explosion, Ground, InvaderGrid, Laser, LaserKind, MysteryShip,
+};
+use crate::events::{DeathCause, EventListener, GameEvent, Listeners};
+use crate::levels::{self, Level};
+use crate::points;
+use crate::replay::{Input, Replay};
+use crate::settings::{Settings, SettingsItem};
+use crate::sound::MARCH_NOTES;
+use crate::stats::{GameStats, LifetimeStats};
+use crate::theme::Theme;
+use crate::ui::Renderer;
 
-#[derive(Clone, Debug)]
+pub const STATS_FILE: &str = "stats.toml";
+pub const ACHIEVEMENTS_FILE: &str = "achievements.toml";
+const BONUS_LIFE_CUE_TICKS: u8 = 40;
+const ACHIEVEMENT_TOAST_TICKS: u8 = 60;
+
+/// How long the title screen is left idle before a demo game starts.
+const ATTRACT_DELAY_MS: u64 = 15_000;
+/// The longest a demo game runs for before returning to the title screen.
+const ATTRACT_LENGTH_MS: u64 = 60_000;
+/// How close an invader laser has to fall before the demo player moves out of its way.
+const DEMO_DODGE_HEIGHT: f64 = 40.0;
+
+/// How long the countdown before a wave starts, or the cannon respawns, lasts.
+const GET_READY_MS: u64 = 3000;
+/// The bonus for clearing a wave, multiplied by the wave number.
+const WAVE_CLEAR_BONUS: u32 = 100;
+/// The bonus for each spare life when a wave is cleared.
+const LIFE_BONUS: u32 = 50;
+
+/// A pause before play starts or resumes, counting down to the action.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GetReady {
+    /// The bonus awarded for the wave that was just cleared, if there was one.
+    pub bonus: Option<WaveBonus>,
+    remaining_ms: u64,
+}
+
+impl GetReady {
+    ///
+    fn new(bonus: Option<WaveBonus>) -> Self {
+        Self {
+            bonus,
+            remaining_ms: GET_READY_MS,
+        }
+    }
+
+    /// The number of seconds left in the countdown, rounded up.
+    pub fn countdown(&self) -> u32 {
+        ((self.remaining_ms + 999) / 1000) as u32
+    }
+}
+
+/// The options in the pause menu.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseMenuItem {
+    Resume,
+    Restart,
+    Settings,
+    /// End the game, counting it towards the lifetime statistics and hiscore, then quit.
+    EndGameAndQuit,
+    Quit,
+}
+
+impl PauseMenuItem {
+    ///
+    pub const ALL: [PauseMenuItem; 5] = [
+        PauseMenuItem::Resume,
+        PauseMenuItem::Restart,
+        PauseMenuItem::Settings,
+        PauseMenuItem::EndGameAndQuit,
+        PauseMenuItem::Quit,
+    ];
+
+    ///
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Resume => "Resume",
+            Self::Restart => "Restart",
+            Self::Settings => "Settings",
+            Self::EndGameAndQuit => "End Game & Quit",
+            Self::Quit => "Quit",
+        }
+    }
+}
+
+/// The points awarded for clearing a wave.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WaveBonus {
+    pub clear: u32,
+    pub lives: u32,
+}
+
+impl WaveBonus {
+    ///
+    pub fn total(&self) -> u32 {
+        self.clear + self.lives
+    }
+}
+
+#[derive(Debug)]
 pub struct App {
     pub started: bool,
     pub game_over: bool,
+    /// Whether the game ended because the invaders reached the cannon's row.
+    pub invaded: bool,
     pub score: u32,
     pub hiscore: u32,
-    level: u8,
+    /// Whether the last game beat the previous hiscore.
+    pub new_hiscore: bool,
+    pub wave: u32,
+    level: Level,
+    /// The countdown shown before each wave and after the cannon respawns, during which the game
+    /// doesn't advance.
+    pub get_ready: Option<GetReady>,
     pub show_help: bool,
     pub paused: bool,
+    pub pause_selected: usize,
+    /// Whether to ask for confirmation before quitting a game in progress.
+    pub confirm_quit: bool,
+    /// Whether "Yes" is selected in the quit confirmation, rather than "No".
+    pub confirm_quit_selected: bool,
     pub should_quit: bool,
+    /// Whether a demo game is being played by the computer, in attract mode.
+    pub demo: bool,
+    /// The last game played to the end, which can be watched again.
+    pub last_game: Option<Replay>,
+    /// The replay being watched, if there is one.
+    pub replay: Option<Replay>,
+    idle_ms: u64,
+    pub show_settings: bool,
+    pub settings_selected: usize,
+    pub settings_message: Option<String>,
+    pub settings: Settings,
+    rules: Settings,
+    config_path: PathBuf,
+    data_dir: Option<PathBuf>,
+    pub show_stats: bool,
+    pub stats: GameStats,
+    pub lifetime: LifetimeStats,
+    pub stats_message: Option<String>,
+    pub show_achievements: bool,
+    pub achievements: Achievements,
+    /// Achievements unlocked during the game that are waiting to be shown, oldest first.
+    pub achievement_toasts: Vec<Achievement>,
+    achievement_toast_ticks: u8,
     pub cannon: Cannon,
+    pub ground: Ground,
     pub bunkers: Bunkers,
+    bunker_shape: BunkerShape,
     pub mystery_ship: MysteryShip,
-    mystery_ship_interval: u16,
     mystery_ship_counter: u16,
     pub grid: InvaderGrid,
     pub cannon_lasers: Vec<Laser>,
     pub invader_lasers: Vec<Laser>,
+    pub explosions: Vec<Explosion>,
     pub lives: u8,
+    pub bonus_life_cue: u8,
+    next_bonus_life: Option<u32>,
+    pub muted: bool,
+    pub zoom: bool,
+    pub no_color: bool,
+    events: Vec<GameEvent>,
+    listeners: Listeners,
+    march_note: u8,
     alien_counter: u8,
     alien_counter_max: u8,
-    invader_laser_counter: u8,
-    count_threshold: usize,
-    max_cannon_lasers: usize,
-    max_invader_lasers: usize,
-    rng: ThreadRng,
+    grid_total: usize,
+    rng: StdRng,
+    /// The number of ticks played in the current game.
+    tick: u64,
+    /// The recording of the current game, unless it is a demo or replay.
+    recording: Option<Replay>,
 }
 
 impl App {
-    ///
+    /// Create a new app. Lifetime statistics and achievements are saved in `data_dir` as they change,
+    /// unless it is `None`. This fails if the bunker shape in the settings is invalid.
     pub fn new(
-        mystery_ship_interval: u16,
-        max_cannon_lasers: u8,
-        max_invader_lasers: u8,
-        level: u8,
-    ) -> Self {
-        let level = if level > MAX_LEVEL { MAX_LEVEL } else { level };
-        let grid = InvaderGrid::new(level);
+        settings: Settings,
+        config_path: PathBuf,
+        lifetime: LifetimeStats,
+        achievements: Achievements,
+        data_dir: Option<PathBuf>,
+    ) -> io::Result<Self> {
+        let bunker_shape = settings
+            .bunker_shape()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        let level = Level::for_wave(settings.start_level, 1);
+        let start_rows = levels::start_rows(&settings.start_rows, settings.start_level, 1);
+        let grid = InvaderGrid::new(&level, start_rows);
 
-        Self {
+        Ok(Self {
             started: false,
             game_over: false,
+            invaded: false,
             score: 0,
-            hiscore: 0,
+            hiscore: lifetime.best_score,
+            new_hiscore: false,
+            wave: 1,
             level,
+            get_ready: None,
             show_help: false,
             paused: false,
+            pause_selected: 0,
+            confirm_quit: false,
+            confirm_quit_selected: false,
             should_quit: false,
+            demo: false,
+            last_game: None,
+            replay: None,
+            idle_ms: 0,
+            show_settings: false,
+            settings_selected: 0,
+            settings_message: None,
+            rules: settings.clone(),
+            config_path,
+            data_dir,
+            show_stats: false,
+            stats: GameStats::default(),
+            lifetime,
+            stats_message: None,
+            show_achievements: false,
+            achievements,
+            achievement_toasts: Vec::new(),
+            achievement_toast_ticks: ACHIEVEMENT_TOAST_TICKS,
             cannon: Cannon::new_normal(),
-            bunkers: Bunkers::new(),
+            ground: Ground::new(),
+            bunkers: Bunkers::with_layout(
+                settings.bunkers,
+                &settings.bunker_positions,
+                &bunker_shape,
+            ),
+            bunker_shape,
             mystery_ship: MysteryShip::new(),
-            mystery_ship_interval,
-            mystery_ship_counter: mystery_ship_interval,
-            count_threshold: grid.count(),
+            mystery_ship_counter: Level::scale(
+                settings.mystery_ship_interval,
+                level.mystery_ship_percent,
+            ),
+            grid_total: grid.count(),
             grid,
             cannon_lasers: Vec::new(),
             invader_lasers: Vec::new(),
-            lives: DEFAULT_LIVES,
-            alien_counter: ALIEN_COUNTER_DEFAULT,
-            alien_counter_max: ALIEN_COUNTER_DEFAULT,
-            invader_laser_counter: INVADER_LASER_COUNTER_DEFAULT,
-            max_cannon_lasers: max_cannon_lasers.into(),
-            max_invader_lasers: max_invader_lasers.into(),
-            rng: rand::thread_rng(),
-        }
+            explosions: Vec::new(),
+            lives: settings.lives,
+            bonus_life_cue: 0,
+            next_bonus_life: Some(settings.bonus_life_score),
+            muted: false,
+            zoom: false,
+            no_color: false,
+            events: Vec::new(),
+            listeners: Listeners::default(),
+            march_note: 0,
+            alien_counter: settings.march_delay,
+            alien_counter_max: settings.march_delay,
+            rng: StdRng::seed_from_u64(0),
+            tick: 0,
+            recording: None,
+            settings,
+        })
     }
 
     ///
     pub fn start(&mut self) {
         self.started = true;
+        self.reset_game();
     }
 
-    ///
+    /// Reset the game, applying any changes made to the settings since the last game started. A
+    /// replay is played with the rules and random numbers of the game it recorded instead.
     pub fn reset_game(&mut self) {
+        let seed = match self.replay.as_mut() {
+            Some(replay) => {
+                replay.rewind();
+                self.rules = replay.rules.clone();
+                replay.seed
+            }
+            None => {
+                self.rules = self.settings.clone();
+                rand::thread_rng().gen()
+            }
+        };
+
+        self.rng = StdRng::seed_from_u64(seed);
+        self.tick = 0;
+        self.recording =
+            (!self.demo && self.replay.is_none()).then(|| Replay::new(seed, self.rules.clone()));
         self.game_over = false;
+        self.invaded = false;
+        self.paused = false;
         self.cannon_lasers.clear();
         self.invader_lasers.clear();
-        self.level = 0;
+        self.explosions.clear();
+        self.wave = 1;
         self.score = 0;
-        self.lives = DEFAULT_LIVES;
-        self.bunkers = Bunkers::new();
+        self.new_hiscore = false;
+        self.stats = GameStats::default();
+        self.achievements.reset_wave();
+        self.achievement_toasts.clear();
+        self.achievement_toast_ticks = ACHIEVEMENT_TOAST_TICKS;
+        self.lives = self.rules.lives;
+        self.bonus_life_cue = 0;
+        self.next_bonus_life = Some(self.rules.bonus_life_score);
+        self.reset_bunkers();
+        self.ground = Ground::new();
         self.mystery_ship.hide();
-        self.mystery_ship_counter = self.mystery_ship_interval;
         self.cannon.reset();
 
         self.reset_grid();
+        self.alien_counter = self.alien_counter_max;
+        self.march_note = 0;
+        self.mystery_ship_counter = self.mystery_ship_interval();
+        self.get_ready = Some(GetReady::new(None));
     }
 
-    ///
+    /// Set up the grid for the current wave.
     pub fn reset_grid(&mut self) {
-        self.grid = InvaderGrid::new(self.level);
-        self.count_threshold = self.grid.count();
-        self.alien_counter_max = ALIEN_COUNTER_DEFAULT;
+        self.level = Level::for_wave(self.rules.start_level, self.wave);
+        let start_rows =
+            levels::start_rows(&self.rules.start_rows, self.rules.start_level, self.wave);
+        self.grid = InvaderGrid::new(&self.level, start_rows);
+        self.grid_total = self.grid.count();
+        self.alien_counter_max = self.rules.march_delay;
     }
 
     ///
     pub fn playing(&self) -> bool {
-        self.started && !self.game_over && !self.paused && !self.show_help
+        self.started
+            && !self.game_over
+            && !self.paused
+            && !self.show_help
+            && self.get_ready.is_none()
     }
 
     ///
@@ -115,6 +350,13 @@ impl App {
             return;
         }
 
+        if self.demo {
+            self.demo_on_tick();
+        }
+
+        self.replay_on_tick();
+        self.tick += 1;
+
         self.mystery_ship_on_tick();
         self.move_grid();
         self.check_collisions();
@@ -124,34 +366,72 @@ impl App {
         }
 
         self.lasers_on_tick();
+        self.explosions_on_tick();
+        self.stats.on_tick(self.rules.tick_length);
+        self.bonus_life_cue = self.bonus_life_cue.saturating_sub(1);
+        self.update_achievement_toasts();
         self.check_collisions();
 
         if self.grid.is_empty() {
-            if self.level < MAX_LEVEL {
-                self.level += 1;
-            } else {
-                self.level = 0;
-            }
-
-            self.reset_grid();
+            self.next_wave();
         } else {
-            self.check_threshold();
+            self.update_march_speed();
         }
     }
 
+    /// Award the bonus for clearing a wave and set up the next one.
+    fn next_wave(&mut self) {
+        let bonus = WaveBonus {
+            clear: WAVE_CLEAR_BONUS * self.wave,
+            lives: LIFE_BONUS * self.lives as u32,
+        };
+
+        self.events.push(GameEvent::WaveCleared {
+            wave: self.wave,
+            bonus: bonus.total(),
+        });
+        self.add_score(bonus.total());
+        self.wave = self.wave.saturating_add(1);
+
+        if let Some(waves) = self.rules.bunker_restore {
+            if (self.wave - 1) % waves == 0 {
+                self.reset_bunkers();
+            }
+        }
+
+        self.cannon_lasers.clear();
+        self.invader_lasers.clear();
+        self.reset_grid();
+        self.get_ready = Some(GetReady::new(Some(bonus)));
+    }
+
     fn mystery_ship_on_tick(&mut self) {
         if self.mystery_ship_counter == 0 {
             if self.mystery_ship.is_visible() {
                 self.mystery_ship.on_tick();
+                self.events.push(GameEvent::MysteryShipMoved);
             } else {
                 self.mystery_ship.reset();
-                self.mystery_ship_counter = self.mystery_ship_interval;
+                self.mystery_ship_counter = self.mystery_ship_interval();
             }
         } else {
             self.mystery_ship_counter -= 1;
         }
     }
 
+    /// The interval between mystery ships for the current wave.
+    fn mystery_ship_interval(&self) -> u16 {
+        Level::scale(
+            self.rules.mystery_ship_interval,
+            self.level.mystery_ship_percent,
+        )
+    }
+
+    /// The maximum number of invader lasers for the current wave.
+    fn max_invader_lasers(&self) -> usize {
+        self.rules.max_invader_lasers as usize + self.level.extra_invader_lasers as usize
+    }
+
     fn lasers_on_tick(&mut self) {
         let mut cannon_lasers_to_delete = vec![];
         let mut invader_lasers_to_delete = vec![];
@@ -168,6 +448,8 @@ impl App {
             laser.on_tick();
 
             if !laser.is_visible() {
+                // bombs that miss everything else land on the ground
+                self.ground.collides_with_laser(laser);
                 invader_lasers_to_delete.push(i);
             }
         }
@@ -181,33 +463,55 @@ impl App {
         }
     }
 
-    fn check_collisions(&mut self) {
-        if !self.grid.is_visible() {
-            self.game_over = true;
-            return;
+    fn explosions_on_tick(&mut self) {
+        for explosion in self.explosions.iter_mut() {
+            explosion.on_tick();
         }
 
-        if self.grid.collides_with_cannon(&self.cannon) {
-            self.game_over = true;
+        self.explosions.retain(|explosion| explosion.is_visible());
+    }
+
+    fn check_collisions(&mut self) {
+        if self.grid.has_invaded() {
+            self.invaded = true;
+            self.events.push(GameEvent::LifeLost {
+                cause: DeathCause::Invaded,
+            });
+            self.end_game();
             return;
         }
 
+        self.check_laser_collisions();
+
         let mut cannon_lasers_to_delete = vec![];
+        let mut score = 0;
 
         for (i, laser) in self.cannon_lasers.iter().enumerate() {
-            if let Some(score) = self.grid.collides_with_laser(laser) {
-                self.score += score;
+            if let Some(invader) = self.grid.collides_with_laser(laser) {
+                let area = invader.area();
+                score += invader.score();
+                self.events.push(GameEvent::InvaderKilled {
+                    invader_type: invader.invader_type(),
+                    position: (area.left, area.bottom),
+                    score: invader.score(),
+                });
                 cannon_lasers_to_delete.push(i);
                 continue;
             }
 
-            if let Some(score) = self.mystery_ship.collides_with(laser) {
-                self.score += score;
+            if let Some(laser_score) = self.mystery_ship.collides_with(laser) {
+                score += laser_score;
+                self.events
+                    .push(GameEvent::MysteryShipHit { score: laser_score });
                 self.mystery_ship.hide();
                 cannon_lasers_to_delete.push(i);
             }
 
             if self.bunkers.collides_with_laser(laser) {
+                let area = laser.area();
+                self.events.push(GameEvent::BunkerDamaged {
+                    position: (area.left, area.bottom),
+                });
                 cannon_lasers_to_delete.push(i);
             }
         }
@@ -219,22 +523,22 @@ impl App {
             self.cannon_lasers.remove(i);
         }
 
+        self.add_score(score);
+
         let mut invader_lasers_to_delete = vec![];
+        let mut cannon_hit = None;
 
         for (i, laser) in self.invader_lasers.iter().enumerate() {
-            if self.cannon.collides_with_laser(laser) {
-                if self.lives == 0 {
-                    self.game_over = true;
-                    return;
-                }
-
-                self.lives -= 1;
-
-                self.cannon.reset();
+            if cannon_hit.is_none() && self.cannon.collides_with_laser(laser) {
+                cannon_hit = Some(laser.kind());
                 invader_lasers_to_delete.push(i);
             }
 
             if self.bunkers.collides_with_laser(laser) {
+                let area = laser.area();
+                self.events.push(GameEvent::BunkerDamaged {
+                    position: (area.left, area.bottom),
+                });
                 invader_lasers_to_delete.push(i);
             }
         }
@@ -242,30 +546,244 @@ impl App {
         // go through in reverse order so we can delete multiple elements in one pass
         // otherwise deleting causes all subsequent elements to move to the previous index
         // and we delete the wrong one (or panic if there's none left)
+        invader_lasers_to_delete.dedup();
+
         for i in invader_lasers_to_delete.into_iter().rev() {
             self.invader_lasers.remove(i);
         }
 
+        if let Some(kind) = cannon_hit {
+            self.lose_life(DeathCause::Bomb(kind));
+
+            if self.game_over {
+                return;
+            }
+        }
+
         for invader in self.grid.collides_with_bunkers(&self.bunkers).into_iter() {
-            self.bunkers.collides_with_invader(invader);
+            if self.bunkers.collides_with_invader(invader) {
+                let area = invader.area();
+                self.events.push(GameEvent::BunkerDamaged {
+                    position: (area.left, area.bottom),
+                });
+            }
+        }
+    }
+
+    /// Destroy the cannon, ending the game if there are no lives left.
+    fn lose_life(&mut self, cause: DeathCause) {
+        self.events.push(GameEvent::LifeLost { cause });
+
+        if self.lives == 0 {
+            self.end_game();
+        } else {
+            self.lives -= 1;
+            self.cannon.reset();
+            self.invader_lasers.clear();
+            self.get_ready = Some(GetReady::new(None));
+        }
+    }
+
+    ///
+    fn end_game(&mut self) {
+        self.game_over = true;
+        self.new_hiscore = self.recording.is_some() && self.score > self.lifetime.best_score;
+
+        if let Some(recording) = self.recording.take() {
+            self.last_game = Some(recording);
+        }
+
+        self.events.push(GameEvent::GameOver {
+            score: self.score,
+            wave: self.wave,
+        });
+    }
+
+    /// Add to the score, awarding a bonus life each time the next bonus life score is passed.
+    fn add_score(&mut self, score: u32) {
+        self.score += score;
+
+        if !self.demo && self.replay.is_none() {
+            self.hiscore = self.hiscore.max(self.score);
+        }
+
+        while let Some(next) = self.next_bonus_life {
+            if self.score < next {
+                break;
+            }
+
+            self.lives = self.lives.saturating_add(1);
+            self.bonus_life_cue = BONUS_LIFE_CUE_TICKS;
+            self.events.push(GameEvent::BonusLife { lives: self.lives });
+            self.next_bonus_life = self.rules.bonus_life_repeat.map(|repeat| next + repeat);
+        }
+    }
+
+    /// Subscribe to the events emitted by the app.
+    pub fn subscribe(&mut self, listener: impl EventListener + 'static) {
+        self.listeners.push(Box::new(listener));
+    }
+
+    /// Take the events emitted since the last call, after updating the statistics with them and
+    /// sending them to the listeners. Listeners that make sounds don't hear anything while muted or
+    /// during a demo.
+    pub fn take_events(&mut self) -> io::Result<Vec<GameEvent>> {
+        let events = self.record_events();
+        let audible = !self.muted && !self.demo;
+
+        for event in events.iter() {
+            self.listeners.on_event(event, audible)?;
+        }
+
+        Ok(events)
+    }
+
+    /// Advance the listeners by a single tick, whether or not a game is being played.
+    pub fn listeners_on_tick(&mut self, tick_length: Duration) -> io::Result<()> {
+        self.listeners.on_tick(tick_length)
+    }
+
+    /// Finish the listeners, once the app has quit.
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.listeners.finish()
+    }
+
+    /// Update the statistics and achievements with the events emitted since the last call, adding
+    /// events for any achievements unlocked. Demos and replays don't count.
+    fn record_events(&mut self) -> Vec<GameEvent> {
+        let mut events = std::mem::take(&mut self.events);
+
+        if self.demo || self.replay.is_some() {
+            return events;
+        }
+
+        for event in events.iter() {
+            // updating in-memory statistics and achievements can't fail
+            let _ = self.stats.on_event(event);
+            let _ = self.achievements.on_event(event);
+
+            if let GameEvent::GameOver { wave, .. } = event {
+                self.lifetime.record(&self.stats, *wave);
+                self.save_stats();
+            }
+        }
+
+        let unlocked = self.achievements.take_unlocked();
+
+        if !unlocked.is_empty() {
+            self.save_achievements();
+            self.achievement_toasts.extend(unlocked.iter());
+            events.extend(
+                unlocked
+                    .into_iter()
+                    .map(|achievement| GameEvent::AchievementUnlocked { achievement }),
+            );
+        }
+
+        events
+    }
+
+    /// Show each unlocked achievement for a while before moving on to the next.
+    fn update_achievement_toasts(&mut self) {
+        if self.achievement_toasts.is_empty() {
+            return;
+        }
+
+        self.achievement_toast_ticks -= 1;
+
+        if self.achievement_toast_ticks == 0 {
+            self.achievement_toasts.remove(0);
+            self.achievement_toast_ticks = ACHIEVEMENT_TOAST_TICKS;
+        }
+    }
+
+    ///
+    fn save_stats(&mut self) {
+        if let Some(data_dir) = &self.data_dir {
+            let path = data_dir.join(STATS_FILE);
+
+            self.stats_message = self
+                .lifetime
+                .save(&path)
+                .err()
+                .map(|err| format!("Failed to save statistics: {err}"));
+        }
+    }
+
+    ///
+    fn save_achievements(&mut self) {
+        if let Some(data_dir) = &self.data_dir {
+            let path = data_dir.join(ACHIEVEMENTS_FILE);
+
+            self.stats_message = self
+                .achievements
+                .save(&path)
+                .err()
+                .map(|err| format!("Failed to save achievements: {err}"));
+        }
+    }
+
+    /// Cancel out cannon lasers that collide with invader lasers. Each kind of bomb may survive the
+    /// collision, depending on the configured survival odds.
+    fn check_laser_collisions(&mut self) {
+        let bomb_survival = self.rules.bomb_survival();
+        let mut cannon_lasers_to_delete = vec![];
+        let mut invader_lasers_to_delete = vec![];
+
+        for (i, cannon_laser) in self.cannon_lasers.iter().enumerate() {
+            for (j, invader_laser) in self.invader_lasers.iter().enumerate() {
+                if invader_lasers_to_delete.contains(&j)
+                    || !cannon_laser.collides_with_laser(invader_laser)
+                {
+                    continue;
+                }
+
+                let area = cannon_laser.area();
+                self.explosions.push(Explosion::new(area.left, area.bottom));
+                self.events.push(GameEvent::LasersCancelled {
+                    position: (area.left, area.bottom),
+                });
+                cannon_lasers_to_delete.push(i);
+
+                let survival = LaserKind::BOMBS
+                    .iter()
+                    .position(|kind| *kind == invader_laser.kind())
+                    .map_or(0, |index| bomb_survival[index]);
+
+                if self.rng.gen_range(0..100) >= survival {
+                    invader_lasers_to_delete.push(j);
+                }
+
+                break;
+            }
+        }
+
+        // go through in reverse order so we can delete multiple elements in one pass
+        for i in cannon_lasers_to_delete.into_iter().rev() {
+            self.cannon_lasers.remove(i);
+        }
+
+        invader_lasers_to_delete.sort_unstable();
+
+        for i in invader_lasers_to_delete.into_iter().rev() {
+            self.invader_lasers.remove(i);
         }
     }
 
     fn move_grid(&mut self) {
         if self.alien_counter == 0 {
             self.grid.move_along();
+            self.events.push(GameEvent::GridMoved {
+                note: self.march_note,
+            });
+            self.march_note = (self.march_note + 1) % MARCH_NOTES;
             self.alien_counter = self.alien_counter_max;
 
-            if self.invader_laser_counter > 0 {
-                self.invader_laser_counter -= 1;
-            }
-
-            if self.invader_laser_counter == 0
-                && self.invader_lasers.len() < self.max_invader_lasers
-            {
-                if let Some(laser) = self.grid.laser(&mut self.rng) {
+            if self.invader_lasers.len() < self.max_invader_lasers() {
+                if let Some(laser) = self.grid.laser(&self.cannon) {
+                    self.events
+                        .push(GameEvent::LaserFired { kind: laser.kind() });
                     self.invader_lasers.push(laser);
-                    self.invader_laser_counter = INVADER_LASER_COUNTER_DEFAULT;
                 }
             }
         } else {
@@ -273,38 +791,302 @@ impl App {
         }
     }
 
-    fn check_threshold(&mut self) {
-        if self.grid.count() <= (self.count_threshold / 2) {
-            self.count_threshold /= 2;
+    /// Speed the grid up according to how many invaders remain.
+    fn update_march_speed(&mut self) {
+        self.alien_counter_max = levels::march_delay(
+            self.rules.march_delay,
+            self.rules.march_curve,
+            self.grid.count(),
+            self.grid_total,
+        );
+        self.alien_counter = self.alien_counter.min(self.alien_counter_max);
+    }
+
+    /// Advance time while no game is being played, starting a demo game once the title screen has
+    /// been idle for long enough and ending it again after a while.
+    pub fn on_idle_tick(&mut self) {
+        self.get_ready_on_tick();
+
+        if self.demo {
+            self.idle_ms += self.tick_length();
+
+            if self.game_over || self.idle_ms >= ATTRACT_LENGTH_MS {
+                self.end_demo();
+            }
+        } else if self.replay.is_some() {
+            if self.game_over {
+                self.end_demo();
+            }
+        } else if !self.started && self.on_title_screen() {
+            self.idle_ms += self.tick_length();
+
+            if self.idle_ms >= ATTRACT_DELAY_MS {
+                self.demo = true;
+                self.idle_ms = 0;
+                self.start();
+            }
+        } else {
+            self.idle_ms = 0;
+        }
+    }
+
+    /// Count down to the start of play.
+    fn get_ready_on_tick(&mut self) {
+        if self.paused || self.show_help {
+            return;
+        }
+
+        let tick_length = self.tick_length();
+
+        if let Some(get_ready) = self.get_ready.as_mut() {
+            get_ready.remaining_ms = get_ready.remaining_ms.saturating_sub(tick_length);
+
+            if get_ready.remaining_ms == 0 {
+                self.get_ready = None;
+            }
+        }
+    }
+
+    /// Note that a key was pressed. Any key ends a demo game or replay and returns to the title
+    /// screen, in which case this returns true and the key should be ignored.
+    pub fn wake(&mut self) -> bool {
+        self.idle_ms = 0;
+
+        if self.demo || self.replay.is_some() {
+            self.end_demo();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn end_demo(&mut self) {
+        self.demo = false;
+        self.replay = None;
+        self.started = false;
+        self.game_over = false;
+        self.idle_ms = 0;
+        self.events.clear();
+    }
+
+    /// Play the demo game: get out of the way of bombs about to land on the cannon, otherwise line
+    /// up with the nearest invader and fire at it.
+    fn demo_on_tick(&mut self) {
+        let cannon = self.cannon.area();
+        let cannon_centre = (cannon.left + cannon.right) / 2.0;
+
+        let threat = self.invader_lasers.iter().map(Laser::area).find(|laser| {
+            laser.bottom < DEMO_DODGE_HEIGHT
+                && laser.right >= cannon.left - points::CANNON_MOVE
+                && laser.left <= cannon.right + points::CANNON_MOVE
+        });
+
+        if let Some(laser) = threat {
+            if laser.left < cannon_centre {
+                self.cannon.move_right();
+            } else {
+                self.cannon.move_left();
+            }
+
+            return;
+        }
+
+        let Some(target) = self.grid.nearest_invader_x(cannon_centre) else {
+            return;
+        };
+
+        if (target - cannon_centre).abs() <= points::CANNON_MOVE {
+            self.on_space();
+        } else if target < cannon_centre {
+            self.cannon.move_left();
+        } else {
+            self.cannon.move_right();
+        }
+    }
+
+    /// Make the moves recorded before this tick of the replay being watched.
+    fn replay_on_tick(&mut self) {
+        while let Some(input) = self
+            .replay
+            .as_mut()
+            .and_then(|replay| replay.next_input(self.tick))
+        {
+            self.play(input);
+        }
+    }
+
+    /// Make a move in the game, recording it so the game can be watched again.
+    fn play(&mut self, input: Input) {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.record(self.tick, input);
+        }
+
+        match input {
+            Input::Left => self.cannon.move_left(),
+            Input::Right => self.cannon.move_right(),
+            Input::Fire => {
+                if self.cannon_lasers.len() < self.rules.max_cannon_lasers as usize {
+                    self.cannon_lasers.push(Laser::new_cannon(self.cannon.left));
+                    self.events.push(GameEvent::LaserFired {
+                        kind: LaserKind::Cannon,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Build the bunkers in the layout from the rules, as they are at the start of a game.
+    fn reset_bunkers(&mut self) {
+        self.bunkers = Bunkers::with_layout(
+            self.rules.bunkers,
+            &self.rules.bunker_positions,
+            &self.bunker_shape,
+        );
+    }
+
+    /// The theme to draw the game in. Unlike the other settings, changes to this apply straight
+    /// away.
+    pub fn theme(&self) -> Theme {
+        self.settings.theme(self.no_color)
+    }
+
+    /// How to draw the game, which like the theme applies straight away.
+    pub fn renderer(&self) -> Renderer {
+        self.settings.renderer
+    }
+
+    /// The number of milliseconds per tick for the current game.
+    pub fn tick_length(&self) -> u64 {
+        self.rules.tick_length
+    }
+
+    /// Check whether a game is in progress, whether or not it is paused.
+    fn in_game(&self) -> bool {
+        self.started && !self.game_over
+    }
+
+    /// Check whether the pause menu is showing and can be navigated.
+    pub fn pause_menu_showing(&self) -> bool {
+        self.paused && !self.confirm_quit && !self.show_settings
+    }
+
+    fn pause(&mut self) {
+        self.paused = true;
+        self.pause_selected = 0;
+    }
+
+    /// Pause a game in progress without the player asking, so it doesn't carry on while they can't
+    /// see it. Demo games carry on regardless.
+    fn auto_pause(&mut self) {
+        if self.in_game() && !self.demo && !self.paused {
+            self.pause();
+        }
+    }
+
+    /// Ask for confirmation before quitting a game in progress, otherwise quit straight away.
+    fn request_quit(&mut self) {
+        if self.in_game() {
+            self.paused = true;
+            self.confirm_quit = true;
+            self.confirm_quit_selected = false;
+        } else {
+            self.should_quit = true;
+        }
+    }
 
-            if self.alien_counter_max > 0 {
-                self.alien_counter_max -= 1;
+    fn select_pause_menu_item(&mut self) {
+        match PauseMenuItem::ALL[self.pause_selected] {
+            PauseMenuItem::Resume => self.paused = false,
+            PauseMenuItem::Restart => self.reset_game(),
+            PauseMenuItem::Settings => {
+                self.show_settings = true;
+                self.settings_message = None;
+            }
+            PauseMenuItem::EndGameAndQuit => {
+                self.end_game();
+                self.should_quit = true;
             }
+            PauseMenuItem::Quit => self.request_quit(),
+        }
+    }
+
+    fn select_confirm_quit(&mut self) {
+        if self.confirm_quit_selected {
+            self.should_quit = true;
+        } else {
+            self.confirm_quit = false;
+        }
+    }
+
+    /// Check whether the title or game over screen is showing, from which the settings and
+    /// statistics screens can be opened.
+    fn on_title_screen(&self) -> bool {
+        (!self.started || self.game_over)
+            && !self.show_settings
+            && !self.show_stats
+            && !self.show_achievements
+    }
+
+    ///
+    pub fn on_up(&mut self) {
+        if self.show_settings {
+            self.settings_selected =
+                (self.settings_selected + SettingsItem::ALL.len() - 1) % SettingsItem::ALL.len();
+        } else if self.pause_menu_showing() {
+            self.pause_selected =
+                (self.pause_selected + PauseMenuItem::ALL.len() - 1) % PauseMenuItem::ALL.len();
+        }
+    }
+
+    ///
+    pub fn on_down(&mut self) {
+        if self.show_settings {
+            self.settings_selected = (self.settings_selected + 1) % SettingsItem::ALL.len();
+        } else if self.pause_menu_showing() {
+            self.pause_selected = (self.pause_selected + 1) % PauseMenuItem::ALL.len();
         }
     }
 
     ///
     pub fn on_left(&mut self) {
-        if self.playing() {
-            self.cannon.move_left();
+        if self.confirm_quit {
+            self.confirm_quit_selected ^= true;
+        } else if self.show_settings {
+            SettingsItem::ALL[self.settings_selected].adjust(&mut self.settings, false);
+            self.settings_message = None;
+        } else if self.playing() {
+            self.play(Input::Left);
         }
     }
 
     ///
     pub fn on_right(&mut self) {
-        if self.playing() {
-            self.cannon.move_right();
+        if self.confirm_quit {
+            self.confirm_quit_selected ^= true;
+        } else if self.show_settings {
+            SettingsItem::ALL[self.settings_selected].adjust(&mut self.settings, true);
+            self.settings_message = None;
+        } else if self.playing() {
+            self.play(Input::Right);
         }
     }
 
     ///
     pub fn on_space(&mut self) {
-        if !self.started {
+        if self.show_settings || self.show_stats || self.show_achievements {
+            return;
+        }
+
+        if self.confirm_quit {
+            self.select_confirm_quit();
+        } else if self.pause_menu_showing() {
+            self.select_pause_menu_item();
+        } else if !self.started {
             self.start()
         } else if self.game_over {
             self.reset_game();
-        } else if self.playing() && self.cannon_lasers.len() < self.max_cannon_lasers {
-            self.cannon_lasers.push(Laser::new_cannon(self.cannon.left));
+        } else if self.playing() {
+            self.play(Input::Fire);
         }
     }
 
@@ -317,14 +1099,126 @@ impl App {
 
     ///
     pub fn on_p(&mut self) {
-        if self.playing() || self.paused {
-            self.paused ^= true;
+        if self.pause_menu_showing() {
+            self.paused = false;
+        } else if self.in_game() && !self.paused && !self.show_help {
+            self.pause();
+        }
+    }
+
+    /// Choose the selected item in the pause menu or quit confirmation.
+    pub fn on_enter(&mut self) {
+        if self.confirm_quit {
+            self.select_confirm_quit();
+        } else if self.pause_menu_showing() {
+            self.select_pause_menu_item();
+        }
+    }
+
+    ///
+    pub fn on_y(&mut self) {
+        if self.confirm_quit {
+            self.should_quit = true;
+        }
+    }
+
+    ///
+    pub fn on_n(&mut self) {
+        self.confirm_quit = false;
+    }
+
+    /// Pause when the terminal loses focus.
+    pub fn on_focus_lost(&mut self) {
+        self.auto_pause();
+    }
+
+    /// Pause when the terminal is too small to show the game.
+    pub fn on_too_small(&mut self) {
+        self.auto_pause();
+    }
+
+    /// Highlight a pause menu item under the mouse.
+    pub fn on_pause_menu_hover(&mut self, index: usize) {
+        if self.pause_menu_showing() && index < PauseMenuItem::ALL.len() {
+            self.pause_selected = index;
+        }
+    }
+
+    /// Choose a pause menu item clicked with the mouse.
+    pub fn on_pause_menu_click(&mut self, index: usize) {
+        if self.pause_menu_showing() && index < PauseMenuItem::ALL.len() {
+            self.pause_selected = index;
+            self.select_pause_menu_item();
+        }
+    }
+
+    /// Choose a button in the quit confirmation clicked with the mouse.
+    pub fn on_confirm_quit_click(&mut self, yes: bool) {
+        if self.confirm_quit {
+            self.confirm_quit_selected = yes;
+            self.select_confirm_quit();
         }
     }
 
+    ///
+    pub fn on_s(&mut self) {
+        if self.show_settings || self.on_title_screen() || self.pause_menu_showing() {
+            self.show_settings ^= true;
+            self.settings_message = None;
+        }
+    }
+
+    /// Write the settings to the config file, if the settings screen is open.
+    pub fn on_w(&mut self) {
+        if !self.show_settings {
+            return;
+        }
+
+        self.settings_message = Some(match self.settings.save(&self.config_path) {
+            Ok(()) => format!("Saved to {}", self.config_path.display()),
+            Err(err) => format!("Failed to save: {err}"),
+        });
+    }
+
+    ///
+    pub fn on_t(&mut self) {
+        if self.show_stats || self.on_title_screen() {
+            self.show_stats ^= true;
+        }
+    }
+
+    /// Watch the last game played to the end again, from the title or game over screen.
+    pub fn on_r(&mut self) {
+        if !self.on_title_screen() || self.last_game.is_none() {
+            return;
+        }
+
+        self.replay = self.last_game.clone();
+        self.start();
+    }
+
+    ///
+    pub fn on_a(&mut self) {
+        if self.show_achievements || self.on_title_screen() {
+            self.show_achievements ^= true;
+        }
+    }
+
+    ///
+    pub fn on_m(&mut self) {
+        self.muted ^= true;
+    }
+
+    ///
+    pub fn on_z(&mut self) {
+        self.zoom ^= true;
+    }
+
     ///
     pub fn on_q(&mut self) {
-        self.should_quit = true;
+        if !self.confirm_quit {
+            self.request_quit();
+        }
     }
 
     ///
@@ -334,6 +1228,492 @@ impl App {
 
     ///
     pub fn on_esc(&mut self) {
-        self.should_quit = true;
+        if self.confirm_quit {
+            self.confirm_quit = false;
+        } else if self.show_settings || self.show_stats || self.show_achievements {
+            self.show_settings = false;
+            self.show_stats = false;
+            self.show_achievements = false;
+        } else if self.paused {
+            self.paused = false;
+        } else if self.show_help {
+            self.show_help = false;
+        } else if self.in_game() {
+            self.pause();
+        } else if self.game_over {
+            self.started = false;
+            self.game_over = false;
+        } else {
+            self.should_quit = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::assets::InvaderType;
+    use crate::points;
+
+    /// Fire a cannon laser straight into a plunger bomb.
+    fn fire_into_bomb(app: &mut App) {
+        let laser = Laser::new_cannon(100.0);
+        let kind = LaserKind::Plunger;
+        let area = laser.area();
+        let bomb = Laser::new_invader(
+            area.left - (points::ALIEN_WIDTH / 2.0).floor() + (kind.width() / 2.0).floor(),
+            area.bottom + kind.height(),
+            InvaderType::Squid,
+            kind,
+        );
+        assert!(laser.collides_with_laser(&bomb));
+
+        app.cannon_lasers.push(laser);
+        app.invader_lasers.push(bomb);
+        app.check_laser_collisions();
+    }
+
+    /// Records the events it hears, sharing them so they can be checked after subscribing.
+    #[derive(Clone, Default)]
+    struct Recorder {
+        events: Rc<RefCell<Vec<GameEvent>>>,
+        audible: bool,
+    }
+
+    impl EventListener for Recorder {
+        fn on_event(&mut self, event: &GameEvent) -> io::Result<()> {
+            self.events.borrow_mut().push(*event);
+            Ok(())
+        }
+
+        fn is_audible(&self) -> bool {
+            self.audible
+        }
+    }
+
+    fn app() -> App {
+        app_with(Settings::default())
+    }
+
+    fn app_with(settings: Settings) -> App {
+        App::new(
+            settings,
+            PathBuf::new(),
+            LifetimeStats::default(),
+            Achievements::default(),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_muted_discards_sounds() {
+        let mut app = app();
+        let sounds = Recorder {
+            audible: true,
+            ..Recorder::default()
+        };
+        let log = Recorder::default();
+        app.subscribe(sounds.clone());
+        app.subscribe(log.clone());
+
+        app.on_m();
+        app.add_score(1500);
+        app.take_events().unwrap();
+
+        assert_eq!(*sounds.events.borrow(), vec![]);
+        assert_eq!(
+            *log.events.borrow(),
+            vec![GameEvent::BonusLife { lives: 4 }]
+        );
+
+        app.on_m();
+        app.events.push(GameEvent::MysteryShipMoved);
+        app.take_events().unwrap();
+
+        assert_eq!(*sounds.events.borrow(), vec![GameEvent::MysteryShipMoved]);
+        assert_eq!(log.events.borrow().len(), 2);
+
+        app.demo = true;
+        app.events.push(GameEvent::MysteryShipMoved);
+        app.take_events().unwrap();
+
+        assert_eq!(sounds.events.borrow().len(), 1);
+        assert_eq!(log.events.borrow().len(), 3);
+    }
+
+    #[test]
+    fn test_lasers_cancel_out() {
+        let mut app = app();
+
+        fire_into_bomb(&mut app);
+
+        assert!(app.cannon_lasers.is_empty());
+        assert!(app.invader_lasers.is_empty());
+        assert_eq!(app.explosions.len(), 1);
+        assert!(matches!(
+            app.take_events().unwrap()[..],
+            [GameEvent::LasersCancelled { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_bomb_survival() {
+        for (survival, bombs_left) in [(0, 0), (100, 1)] {
+            let settings = Settings {
+                plunger_survival: survival,
+                ..Settings::default()
+            };
+            let mut app = app_with(settings);
+            app.start();
+
+            fire_into_bomb(&mut app);
+
+            // the cannon laser is always destroyed, while the bomb may carry on
+            assert!(app.cannon_lasers.is_empty());
+            assert_eq!(app.invader_lasers.len(), bombs_left, "{survival}% survival");
+        }
+    }
+
+    #[test]
+    fn test_bonus_life_awarded_once() {
+        let mut app = app();
+
+        app.add_score(1490);
+        assert_eq!(app.lives, 3);
+
+        app.add_score(10);
+        assert_eq!(app.lives, 4);
+        assert_eq!(
+            app.take_events().unwrap(),
+            vec![GameEvent::BonusLife { lives: 4 }]
+        );
+        assert_eq!(app.take_events().unwrap(), vec![]);
+
+        app.add_score(5000);
+        assert_eq!(app.lives, 4);
+    }
+
+    #[test]
+    fn test_bonus_life_repeats() {
+        let settings = Settings {
+            bonus_life_repeat: Some(1000),
+            ..Settings::default()
+        };
+        let mut app = app_with(settings);
+
+        app.add_score(1500);
+        assert_eq!(app.lives, 4);
+
+        app.add_score(2000);
+        assert_eq!(app.lives, 6);
+    }
+
+    #[test]
+    fn test_last_life_lost_ends_game() {
+        let mut app = app();
+        app.start();
+        app.lives = 0;
+
+        app.lose_life(DeathCause::Bomb(LaserKind::Rolling));
+
+        assert!(app.game_over);
+        assert_eq!(app.hiscore, 0);
+        assert_eq!(
+            app.take_events().unwrap(),
+            vec![
+                GameEvent::LifeLost {
+                    cause: DeathCause::Bomb(LaserKind::Rolling)
+                },
+                GameEvent::GameOver { score: 0, wave: 1 },
+            ]
+        );
+        assert_eq!(app.lifetime.games_played, 1);
+        assert_eq!(app.lifetime.totals.lives_lost.rolling, 1);
+    }
+
+    #[test]
+    fn test_new_hiscore_summary() {
+        let mut app = app();
+        app.lifetime.best_score = 100;
+        app.start();
+        app.lives = 0;
+        app.add_score(150);
+
+        app.lose_life(DeathCause::Invaded);
+        app.take_events().unwrap();
+
+        assert!(app.new_hiscore);
+        assert_eq!(app.lifetime.best_score, 150);
+
+        app.on_esc();
+
+        assert!(!app.started);
+        assert!(!app.should_quit);
+    }
+
+    /// Play a game until it ends, or watch a replay to the end, returning the final score, wave and
+    /// number of ticks played.
+    fn play_to_end(app: &mut App) -> (u32, u32, u64) {
+        for tick in 0..100_000 {
+            if app.game_over {
+                break;
+            }
+
+            if !app.playing() {
+                app.on_idle_tick();
+                continue;
+            }
+
+            if app.replay.is_none() {
+                match tick % 7 {
+                    0 | 3 => app.on_space(),
+                    1 | 2 => app.on_left(),
+                    _ => app.on_right(),
+                }
+            }
+
+            app.on_tick();
+            app.take_events().unwrap();
+        }
+
+        assert!(app.game_over);
+        (app.score, app.wave, app.tick)
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut app = app_with(Settings {
+            rolling_survival: 50,
+            plunger_survival: 50,
+            squiggly_survival: 50,
+            ..Settings::default()
+        });
+        app.on_r();
+        assert!(!app.started);
+
+        app.start();
+        let game = play_to_end(&mut app);
+        assert!(game.0 > 0);
+
+        // the replay keeps to the rules the game was played with
+        app.settings.lives = 1;
+        app.on_r();
+        assert!(app.replay.is_some());
+        assert_eq!(play_to_end(&mut app), game);
+        assert_eq!(app.lifetime.games_played, 1);
+
+        app.on_idle_tick();
+        assert!(app.replay.is_none());
+        assert!(!app.started);
+
+        app.on_r();
+        assert!(app.wake());
+        assert!(app.replay.is_none());
+        assert!(app.last_game.is_some());
+    }
+
+    #[test]
+    fn test_get_ready_after_respawn() {
+        let mut app = app();
+        app.start();
+
+        assert!(!app.playing());
+        assert_eq!(app.get_ready.unwrap().countdown(), 3);
+
+        while app.get_ready.is_some() {
+            app.on_idle_tick();
+        }
+
+        assert!(app.playing());
+
+        app.lose_life(DeathCause::Invaded);
+
+        assert!(!app.playing());
+        assert_eq!(app.get_ready, Some(GetReady::new(None)));
+    }
+
+    #[test]
+    fn test_bunker_restore() {
+        let settings = Settings {
+            bunker_restore: Some(2),
+            ..Settings::default()
+        };
+        let mut app = app_with(settings);
+        app.start();
+
+        let fresh = app.bunkers.clone();
+        let mut laser = Laser::new_cannon(20.0);
+        while !app.bunkers.collides_with_laser(&laser) {
+            assert!(laser.is_visible(), "the laser missed the bunkers");
+            laser.on_tick();
+        }
+
+        // wave 2 keeps the damage, then wave 3 restores the bunkers
+        app.next_wave();
+        assert_ne!(app.bunkers, fresh);
+
+        app.next_wave();
+        assert_eq!(app.bunkers, fresh);
+    }
+
+    #[test]
+    fn test_invalid_bunker_shape() {
+        let settings = Settings {
+            bunker_shape: Some(vec![]),
+            ..Settings::default()
+        };
+
+        assert!(App::new(
+            settings,
+            PathBuf::new(),
+            LifetimeStats::default(),
+            Achievements::default(),
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_invasion_ends_game() {
+        let mut app = app();
+        app.start();
+
+        // the invaders end the game even when the cannon is out of their way
+        while app.cannon.left < points::GAME_WIDTH - points::CANNON_WIDTH {
+            app.cannon.move_right();
+        }
+
+        while !app.game_over {
+            app.grid.move_along();
+            app.check_collisions();
+        }
+
+        assert!(app.invaded);
+        assert!(app.lives > 0);
+
+        app.reset_game();
+        assert!(!app.invaded);
+    }
+
+    #[test]
+    fn test_bomb_damages_ground() {
+        let mut app = app();
+        app.start();
+
+        app.invader_lasers.push(Laser::new_invader(
+            100.0,
+            points::BOMB_HEIGHT,
+            InvaderType::Squid,
+            LaserKind::Plunger,
+        ));
+
+        while !app.invader_lasers.is_empty() {
+            app.lasers_on_tick();
+        }
+
+        assert_ne!(app.ground, Ground::new());
+
+        app.reset_game();
+        assert_eq!(app.ground, Ground::new());
+    }
+
+    #[test]
+    fn test_attract_mode() {
+        let mut app = app();
+
+        while !app.demo {
+            app.on_idle_tick();
+        }
+
+        assert!(app.started);
+        assert_eq!(app.idle_ms, 0);
+
+        app.lives = 0;
+        app.lose_life(DeathCause::Invaded);
+        app.take_events().unwrap();
+
+        assert_eq!(app.lifetime.games_played, 0);
+
+        app.on_idle_tick();
+
+        assert!(!app.demo);
+        assert!(!app.started);
+        assert!(!app.wake());
+    }
+
+    #[test]
+    fn test_settings_apply_to_next_game() {
+        let mut app = app();
+        app.start();
+
+        app.settings.lives = 5;
+        assert_eq!(app.lives, 3);
+
+        app.reset_game();
+        assert_eq!(app.lives, 5);
+    }
+
+    #[test]
+    fn test_pause_menu() {
+        let mut app = app();
+        app.start();
+
+        app.on_p();
+        assert!(app.pause_menu_showing());
+
+        app.on_up();
+        assert_eq!(PauseMenuItem::ALL[app.pause_selected], PauseMenuItem::Quit);
+
+        app.on_enter();
+        assert!(app.confirm_quit);
+
+        app.on_n();
+        assert!(app.pause_menu_showing());
+        assert!(!app.should_quit);
+
+        app.on_down();
+        app.on_space();
+        assert!(!app.paused);
+    }
+
+    #[test]
+    fn test_quit_asks_for_confirmation() {
+        let mut app = app();
+        app.start();
+
+        app.on_q();
+        assert!(app.confirm_quit);
+        assert!(!app.should_quit);
+
+        app.on_esc();
+        assert!(!app.confirm_quit);
+
+        app.on_q();
+        app.on_y();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_auto_pause() {
+        let mut app = app();
+
+        app.on_focus_lost();
+        assert!(!app.paused);
+
+        app.start();
+        app.on_too_small();
+        assert!(app.pause_menu_showing());
+
+        app.pause_selected = 1;
+        app.on_focus_lost();
+        assert_eq!(app.pause_selected, 1);
+
+        app.on_esc();
+        app.on_idle_tick();
+        assert!(!app.paused);
     }
 }
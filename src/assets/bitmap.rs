@@ -0,0 +1,118 @@
+//!
+
+/// A grid of pixels stored as a bit each, so single pixels can be tested and changed in constant
+/// time. Rows are at most [`Bitmap::MAX_WIDTH`] pixels wide, which is plenty for any sprite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bitmap {
+    width: i32,
+    height: i32,
+    rows: Vec<u32>,
+}
+
+impl Bitmap {
+    /// The widest a bitmap can be, as each row is stored in a `u32`.
+    pub const MAX_WIDTH: i32 = u32::BITS as i32;
+
+    /// Create a bitmap with the given points set, where `(0, 0)` is the bottom left pixel. Bitmaps
+    /// wider than [`Bitmap::MAX_WIDTH`] are cut down to it, dropping any points to the right.
+    pub fn from_points(width: f64, height: f64, points: &[(f64, f64)]) -> Self {
+        let mut bitmap = Self {
+            width: (width as i32).min(Self::MAX_WIDTH),
+            height: height as i32,
+            rows: vec![0; height as usize],
+        };
+
+        for (x, y) in points {
+            bitmap.set(*x as i32, *y as i32);
+        }
+
+        bitmap
+    }
+
+    /// Check whether a pixel is set. Pixels outside the bitmap are never set.
+    pub fn get(&self, x: i32, y: i32) -> bool {
+        self.contains(x, y) && self.rows[y as usize] & (1 << x) != 0
+    }
+
+    /// Check whether a point overlaps any set pixel. The point doesn't need to be aligned to the
+    /// pixels, in which case it overlaps up to four of them.
+    pub fn hits(&self, x: f64, y: f64) -> bool {
+        let (left, bottom) = (x.floor() as i32, y.floor() as i32);
+        let (right, top) = (x.ceil() as i32, y.ceil() as i32);
+
+        self.get(left, bottom)
+            || self.get(right, bottom)
+            || self.get(left, top)
+            || self.get(right, top)
+    }
+
+    ///
+    pub fn set(&mut self, x: i32, y: i32) {
+        if self.contains(x, y) {
+            self.rows[y as usize] |= 1 << x;
+        }
+    }
+
+    /// Clear a pixel, returning whether it was set.
+    pub fn clear(&mut self, x: i32, y: i32) -> bool {
+        let was_set = self.get(x, y);
+
+        if was_set {
+            self.rows[y as usize] &= !(1 << x);
+        }
+
+        was_set
+    }
+
+    /// The pixels that are set, from the bottom left.
+    pub fn points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.rows.iter().enumerate().flat_map(move |(y, row)| {
+            (0..self.width)
+                .filter(move |x| row & (1 << x) != 0)
+                .map(move |x| (x as f64, y as f64))
+        })
+    }
+
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && x < self.width && y < self.height
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bitmap() {
+        let mut bitmap = Bitmap::from_points(4.0, 2.0, &[(0.0, 0.0), (3.0, 1.0)]);
+
+        assert!(bitmap.get(0, 0));
+        assert!(bitmap.get(3, 1));
+        assert!(!bitmap.get(1, 0));
+        assert!(!bitmap.get(-1, 0));
+        assert!(!bitmap.get(4, 1));
+
+        assert!(bitmap.hits(0.0, 0.0));
+        assert!(bitmap.hits(2.5, 0.5));
+        assert!(!bitmap.hits(1.0, 0.0));
+        assert!(!bitmap.hits(1.5, 1.0));
+
+        assert!(bitmap.clear(3, 1));
+        assert!(!bitmap.clear(3, 1));
+        assert!(!bitmap.clear(10, 10));
+        assert_eq!(bitmap.points().collect::<Vec<_>>(), vec![(0.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_bitmap_too_wide() {
+        let mut bitmap = Bitmap::from_points(40.0, 1.0, &[(31.0, 0.0), (32.0, 0.0), (39.0, 0.0)]);
+
+        assert!(bitmap.get(31, 0));
+        assert!(!bitmap.get(32, 0));
+        assert!(!bitmap.get(39, 0));
+
+        bitmap.set(35, 0);
+        assert!(!bitmap.clear(35, 0));
+        assert_eq!(bitmap.points().collect::<Vec<_>>(), vec![(31.0, 0.0)]);
+    }
+}
@@ -1,35 +1,41 @@
 //!
 
-use tui::style::Color;
-use tui::widgets::canvas::{Painter, Shape};
+use tui::widgets::canvas::Painter;
 
 use crate::points;
+use crate::theme::Role;
 
-use super::{Area, Invader, Laser};
+use super::{Area, Bitmap, Invader, Laser, LaserKind, Palette, Sprite};
 
-const BUNKER_SPACING: f64 =
-    (points::GAME_WIDTH - (2.0 * points::BUNKER_OFFSET_X) - (4.0 * points::BUNKER_WIDTH)) / 3.0;
+/// The widest and tallest a bunker can be, limited by the width of its bitmap.
+const MAX_BUNKER_SIZE: usize = Bitmap::MAX_WIDTH as usize;
 
 ///
 #[derive(Clone, Debug, PartialEq)]
 pub struct Bunkers {
     bunkers: Vec<Bunker>,
+    height: f64,
 }
 
 impl Bunkers {
-    ///
-    pub fn new() -> Self {
-        let mut bunkers = Vec::new();
+    /// Build bunkers with their left edges at the given positions or, if there are none, spread
+    /// `count` of them evenly across the playfield.
+    pub fn with_layout(count: u8, positions: &[f64], shape: &BunkerShape) -> Self {
+        let positions = if positions.is_empty() {
+            spread(count, shape.width())
+        } else {
+            positions.to_vec()
+        };
 
-        for i in 0..4 {
-            let x_offset =
-                points::BUNKER_OFFSET_X + (i as f64 * (points::BUNKER_WIDTH + BUNKER_SPACING));
-            let bunker = Bunker::new(x_offset, points::BUNKER_INITIAL_Y);
+        let bunkers = positions
+            .into_iter()
+            .map(|left| Bunker::new(left, points::BUNKER_INITIAL_Y, shape))
+            .collect();
 
-            bunkers.push(bunker)
+        Self {
+            bunkers,
+            height: shape.height(),
         }
-
-        Self { bunkers }
     }
 
     ///
@@ -46,29 +52,118 @@ impl Bunkers {
     }
 
     ///
-    pub fn collides_with_invader(&mut self, invader: &Invader) {
+    pub fn collides_with_invader(&mut self, invader: &Invader) -> bool {
         for bunker in self.bunkers.iter_mut() {
             if bunker.collides_with_invader(invader) {
-                break;
+                return true;
             }
         }
+
+        false
     }
 
-    //
+    /// The band of the playfield the bunkers are in.
     pub fn area(&self) -> Area {
         Area::new(
             0.0,
             points::BUNKER_INITIAL_Y,
             points::GAME_WIDTH,
-            points::BUNKER_INITIAL_Y + points::BUNKER_HEIGHT,
+            points::BUNKER_INITIAL_Y + self.height,
         )
     }
 }
 
-impl Shape for Bunkers {
-    fn draw(&self, painter: &mut Painter) {
+/// The left edges of bunkers spread evenly across the playfield, with the outer ones the same
+/// distance from the edges as in the arcade original.
+fn spread(count: u8, width: f64) -> Vec<f64> {
+    let usable = points::GAME_WIDTH - (2.0 * points::BUNKER_OFFSET_X) - width;
+
+    match count {
+        0 => Vec::new(),
+        1 => vec![(points::GAME_WIDTH - width) / 2.0],
+        _ => (0..count)
+            .map(|i| points::BUNKER_OFFSET_X + (i as f64 * usable / (count - 1) as f64))
+            .collect(),
+    }
+}
+
+/// The shape of a bunker before it takes any damage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BunkerShape {
+    pixels: Bitmap,
+    width: usize,
+    height: usize,
+}
+
+impl BunkerShape {
+    /// The arched bunker from the arcade original.
+    pub fn classic() -> Self {
+        Self {
+            pixels: Bitmap::from_points(
+                points::BUNKER_WIDTH,
+                points::BUNKER_HEIGHT,
+                &points::BUNKER,
+            ),
+            width: points::BUNKER_WIDTH as usize,
+            height: points::BUNKER_HEIGHT as usize,
+        }
+    }
+
+    /// Parse a shape drawn as rows of text from the top down, where `#` is solid and anything else
+    /// is empty.
+    pub fn parse(rows: &[String]) -> Result<Self, String> {
+        let height = rows.len();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        if width == 0 || height == 0 {
+            return Err("bunker shape is empty".to_string());
+        }
+
+        if width > MAX_BUNKER_SIZE || height > MAX_BUNKER_SIZE {
+            return Err(format!(
+                "bunker shape is {width}x{height}, but can be at most {MAX_BUNKER_SIZE}x{MAX_BUNKER_SIZE}"
+            ));
+        }
+
+        let points: Vec<_> = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                let y = (height - 1 - i) as f64;
+
+                row.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| (x as f64, y))
+            })
+            .collect();
+
+        Ok(Self {
+            pixels: Bitmap::from_points(width as f64, height as f64, &points),
+            width,
+            height,
+        })
+    }
+
+    ///
+    pub fn width(&self) -> f64 {
+        self.width as f64
+    }
+
+    ///
+    pub fn height(&self) -> f64 {
+        self.height as f64
+    }
+}
+
+impl Sprite for Bunkers {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
         for bunker in self.bunkers.iter() {
-            bunker.draw(painter);
+            bunker.paint(painter, palette);
         }
     }
 }
@@ -78,69 +173,88 @@ impl Shape for Bunkers {
 pub struct Bunker {
     left: f64,
     bottom: f64,
-    color: Color,
-    data: Vec<(f64, f64)>,
+    width: f64,
+    height: f64,
+    pixels: Bitmap,
 }
 
 impl Bunker {
     ///
-    pub fn new(left: f64, bottom: f64) -> Self {
+    pub fn new(left: f64, bottom: f64, shape: &BunkerShape) -> Self {
         Self {
             left,
             bottom,
-            color: Color::Green,
-            data: points::BUNKER.into(),
+            width: shape.width(),
+            height: shape.height(),
+            pixels: shape.pixels.clone(),
         }
     }
 
-    ///
+    /// Check whether a laser has hit the bunker, knocking a ragged hole out of it where it hit.
     pub fn collides_with_laser(&mut self, laser: &Laser) -> bool {
-        let mut collision = false;
-        let mut to_delete = vec![];
-        let collision_area = laser.bunker_collision_area();
+        let area = self.area();
 
-        if self.area().overlaps(laser.area()) {
-            for (i, (x, y)) in self.data.iter().enumerate() {
-                let x = x + self.left;
-                let y = y + self.bottom;
-
-                if collision_area.contains(x, y) {
-                    to_delete.push(i);
-                    collision = true;
-                }
-            }
+        if !area.overlaps(laser.area()) {
+            return false;
         }
 
-        for i in to_delete.into_iter().rev() {
-            self.data.remove(i);
-        }
+        // the laser hit wherever its leading edge touched first
+        let direction = laser.kind().movement().signum() as i32;
+        let impact = laser
+            .points()
+            .filter(|(x, y)| area.contains(*x, *y))
+            .map(|(x, y)| self.pixel_at(x, y))
+            .filter(|(x, y)| self.pixels.get(*x, *y))
+            .max_by_key(|(_, y)| y * direction);
 
-        collision
+        match impact {
+            Some(impact) => {
+                self.erode(impact, laser.kind());
+                true
+            }
+            None => false,
+        }
     }
 
-    ///
+    /// Check whether an invader has walked into the bunker, clearing everything it overlaps.
     pub fn collides_with_invader(&mut self, invader: &Invader) -> bool {
-        let mut collision = false;
-        let mut to_delete = vec![];
         let invader_area = invader.area();
 
-        if self.area().overlaps(invader_area) {
-            for (i, (x, y)) in self.data.iter().enumerate() {
-                let x = x + self.left;
-                let y = y + self.bottom;
+        if !self.area().overlaps(invader_area) {
+            return false;
+        }
 
-                if invader_area.contains(x, y) {
-                    to_delete.push(i);
-                    collision = true;
-                }
+        let (left, bottom) = self.pixel_at(invader_area.left, invader_area.bottom);
+        let (right, top) = self.pixel_at(invader_area.right, invader_area.top);
+        let mut collision = false;
+
+        for y in bottom..top {
+            for x in left..right {
+                collision |= self.pixels.clear(x, y);
             }
         }
 
-        for i in to_delete.into_iter().rev() {
-            self.data.remove(i);
+        collision
+    }
+
+    /// Knock the laser's mask out of the bunker, centred on the pixel it hit.
+    fn erode(&mut self, (x, y): (i32, i32), kind: LaserKind) {
+        let left = x - (kind.bunker_mask_width() / 2.0) as i32;
+        let bottom = y - (points::MASK_HEIGHT / 2.0) as i32;
+
+        for (mask_x, mask_y) in kind.bunker_mask() {
+            self.pixels
+                .clear(left + *mask_x as i32, bottom + *mask_y as i32);
         }
+    }
 
-        collision
+    /// The pixel of the bunker at a point on the playfield. Bunkers are spaced evenly, so aren't
+    /// always aligned to whole points.
+    fn pixel_at(&self, x: f64, y: f64) -> (i32, i32) {
+        (
+            (x - self.left).round() as i32,
+            (y - self.bottom).round() as i32,
+        )
     }
 
     //
@@ -148,21 +262,98 @@ impl Bunker {
         Area::new(
             self.left,
             self.bottom,
-            self.left + points::BUNKER_WIDTH,
-            self.bottom + points::BUNKER_HEIGHT,
+            self.left + self.width,
+            self.bottom + self.height,
         )
     }
 }
 
-impl Shape for Bunker {
-    fn draw(&self, painter: &mut Painter) {
-        for (x, y) in self.data.iter() {
+impl Sprite for Bunker {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
+        for (x, y) in self.pixels.points() {
             let x = x + self.left;
             let y = y + self.bottom;
 
-            if let Some((x, y)) = painter.get_point(x, y) {
-                painter.paint(x, y, self.color);
-            }
+            super::paint_point(painter, palette, x, y, Role::Bunker);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_spread_matches_arcade() {
+        let lefts = spread(4, points::BUNKER_WIDTH);
+        let spacing = (points::GAME_WIDTH - 40.0 - 80.0) / 3.0;
+
+        assert_eq!(lefts.len(), 4);
+        for (i, left) in lefts.into_iter().enumerate() {
+            let expected = 20.0 + (i as f64 * (points::BUNKER_WIDTH + spacing));
+            assert!((left - expected).abs() < 1e-9, "{left} != {expected}");
+        }
+
+        assert_eq!(spread(0, points::BUNKER_WIDTH), Vec::<f64>::new());
+        assert_eq!(spread(1, points::BUNKER_WIDTH), vec![90.0]);
+    }
+
+    #[test]
+    fn test_no_bunkers() {
+        let mut bunkers = Bunkers::with_layout(0, &[], &BunkerShape::classic());
+        let mut laser = Laser::new_cannon(20.0);
+
+        while laser.is_visible() {
+            assert!(!bunkers.collides_with_laser(&laser));
+            laser.on_tick();
+        }
+    }
+
+    #[test]
+    fn test_parse_shape() {
+        let rows = vec![" ## ".to_string(), "####".to_string(), "#  #".to_string()];
+        let shape = BunkerShape::parse(&rows).unwrap();
+
+        assert_eq!((shape.width(), shape.height()), (4.0, 3.0));
+        assert!(shape.pixels.get(0, 0));
+        assert!(!shape.pixels.get(1, 0));
+        assert!(shape.pixels.get(1, 2));
+        assert!(!shape.pixels.get(0, 2));
+
+        assert!(BunkerShape::parse(&[]).is_err());
+        assert!(BunkerShape::parse(&["#".repeat(33)]).is_err());
+    }
+
+    #[test]
+    fn test_laser_erodes_bunker() {
+        let mut bunker = Bunker::new(0.0, 20.0, &BunkerShape::classic());
+        let before = bunker.pixels.points().count();
+
+        // fire up through the middle of the arch, so the laser hits its underside
+        let mut laser = Laser::new_cannon(10.0 - points::CANNON_LASER_INITIAL_X_OFFSET);
+
+        while !bunker.collides_with_laser(&laser) {
+            laser.on_tick();
+            assert!(laser.is_visible(), "laser passed through the bunker");
+        }
+
+        let removed: Vec<_> = Bunker::new(0.0, 20.0, &BunkerShape::classic())
+            .pixels
+            .points()
+            .filter(|(x, y)| !bunker.pixels.get(*x as i32, *y as i32))
+            .collect();
+
+        assert_eq!(removed.len(), before - bunker.pixels.points().count());
+        assert!(!removed.is_empty());
+        assert!(removed.len() <= points::CANNON_LASER_MASK.len());
+
+        // the damage stays around where the laser hit, rather than cutting a slot through
+        for (x, y) in removed {
+            assert!(
+                (6.0..14.0).contains(&x),
+                "({x}, {y}) is too far from the impact"
+            );
         }
+        assert!(bunker.pixels.get(10, points::BUNKER_HEIGHT as i32 - 1));
     }
 }
@@ -1,18 +1,19 @@
 //!
 
-use tui::style::Color;
-use tui::widgets::canvas::{Painter, Shape};
+use std::sync::OnceLock;
+
+use tui::widgets::canvas::Painter;
 
 use crate::points;
+use crate::theme::Role;
 
-use super::{Area, Laser};
+use super::{Area, Bitmap, Laser, Palette, Sprite};
 
 ///
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Cannon {
     pub left: f64,
     bottom: f64,
-    color: Color,
 }
 
 impl Cannon {
@@ -21,18 +22,16 @@ impl Cannon {
         Self {
             left: points::CANNON_INITIAL_X,
             bottom: points::CANNON_INITIAL_Y,
-            color: Color::Green,
         }
     }
 
     ///
-    pub fn new_life(index: u8) -> Self {
-        let x_offset = (index - 1) as f64 * (points::CANNON_WIDTH + 4.0);
+    pub fn new_life(index: u16) -> Self {
+        let x_offset = (index - 1) as f64 * (points::CANNON_WIDTH + points::LIFE_SPACING_X);
 
         Self {
             left: points::CANNON_INITIAL_X + x_offset,
-            bottom: points::CANNON_INITIAL_Y,
-            color: Color::Green,
+            bottom: points::LIFE_INITIAL_Y,
         }
     }
 
@@ -56,9 +55,18 @@ impl Cannon {
         self.bottom = points::CANNON_INITIAL_Y;
     }
 
-    ///
+    /// Check whether a laser has hit the cannon itself, rather than just passed through the space
+    /// around its turret.
     pub fn collides_with_laser(&self, laser: &Laser) -> bool {
-        self.area().overlaps(laser.area())
+        if !self.area().overlaps(laser.area()) {
+            return false;
+        }
+
+        let mask = mask();
+
+        laser
+            .points()
+            .any(|(x, y)| mask.hits(x - self.left, y - self.bottom))
     }
 
     ///
@@ -72,15 +80,51 @@ impl Cannon {
     }
 }
 
-impl Shape for Cannon {
-    fn draw(&self, painter: &mut Painter) {
+/// The collision mask for the cannon, built from its sprite the first time it's needed.
+fn mask() -> &'static Bitmap {
+    static MASK: OnceLock<Bitmap> = OnceLock::new();
+
+    MASK.get_or_init(|| {
+        Bitmap::from_points(points::CANNON_WIDTH, points::CANNON_HEIGHT, &points::CANNON)
+    })
+}
+
+impl Sprite for Cannon {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
         for (x, y) in &points::CANNON {
             let x = x + self.left;
             let y = y + self.bottom;
 
-            if let Some((x, y)) = painter.get_point(x, y) {
-                painter.paint(x, y, self.color);
-            }
+            super::paint_point(painter, palette, x, y, Role::Cannon);
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::{InvaderType, LaserKind};
+
+    #[test]
+    fn test_bomb_misses_beside_turret() {
+        let cannon = Cannon::new_normal();
+
+        // bombs drop from the middle of an invader, so offset it to put the bomb's left edge at
+        // `left` along the cannon, overlapping just the top row where only the turret is
+        let bomb_at = |left: f64| {
+            Laser::new_invader(
+                cannon.left + left - 5.0,
+                cannon.bottom + points::CANNON_HEIGHT + points::BOMB_HEIGHT - 1.0,
+                InvaderType::Crab,
+                LaserKind::Plunger,
+            )
+        };
+
+        let beside = bomb_at(0.0);
+        assert!(cannon.area().overlaps(beside.area()));
+        assert!(!cannon.collides_with_laser(&beside));
+
+        let on_turret = bomb_at((points::CANNON_WIDTH / 2.0).floor() - 1.0);
+        assert!(cannon.collides_with_laser(&on_turret));
+    }
+}
@@ -0,0 +1,48 @@
+//!
+
+use tui::widgets::canvas::Painter;
+
+use crate::points;
+use crate::theme::Role;
+
+use super::{Palette, Sprite};
+
+/// A short-lived explosion, shown where two lasers cancel each other out.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Explosion {
+    left: f64,
+    bottom: f64,
+    ticks: u8,
+}
+
+impl Explosion {
+    /// Create a new explosion centred horizontally on `x`.
+    pub fn new(x: f64, bottom: f64) -> Self {
+        Self {
+            left: x - (points::EXPLOSION_WIDTH / 2.0).floor(),
+            bottom,
+            ticks: points::EXPLOSION_TICKS,
+        }
+    }
+
+    ///
+    pub fn on_tick(&mut self) {
+        self.ticks = self.ticks.saturating_sub(1);
+    }
+
+    ///
+    pub fn is_visible(&self) -> bool {
+        self.ticks > 0
+    }
+}
+
+impl Sprite for Explosion {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
+        for (x, y) in &points::EXPLOSION {
+            let x = x + self.left;
+            let y = y + self.bottom;
+
+            super::paint_point(painter, palette, x, y, Role::Explosion);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//!
+
+use tui::widgets::canvas::Painter;
+
+use crate::points;
+use crate::theme::Role;
+
+use super::{Laser, Palette, Sprite};
+
+/// The line along the bottom of the playfield, under the cannon. Bombs that miss the cannon leave
+/// holes in it where they land.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ground {
+    pixels: Vec<bool>,
+}
+
+impl Ground {
+    ///
+    pub fn new() -> Self {
+        Self {
+            pixels: vec![true; points::GAME_WIDTH as usize],
+        }
+    }
+
+    /// Knock a hole in the ground under a bomb that has reached the bottom of the playfield,
+    /// returning whether any of the ground was left there to hit.
+    pub fn collides_with_laser(&mut self, laser: &Laser) -> bool {
+        let area = laser.area();
+        let left = area.left.round().max(0.0) as usize;
+        let right = (area.right.round() as usize).min(self.pixels.len());
+
+        let mut hit = false;
+
+        for pixel in self.pixels.iter_mut().take(right).skip(left) {
+            hit |= *pixel;
+            *pixel = false;
+        }
+
+        hit
+    }
+}
+
+impl Default for Ground {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sprite for Ground {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
+        for (x, _) in self.pixels.iter().enumerate().filter(|(_, pixel)| **pixel) {
+            super::paint_point(painter, palette, x as f64, points::GROUND_Y, Role::Cannon);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::{InvaderType, LaserKind};
+
+    #[test]
+    fn test_bomb_leaves_hole() {
+        let mut ground = Ground::new();
+        let bomb = Laser::new_invader(50.0, 4.0, InvaderType::Squid, LaserKind::Rolling);
+
+        assert!(ground.collides_with_laser(&bomb));
+        assert!(!ground.collides_with_laser(&bomb));
+
+        let holes: Vec<_> = (0..ground.pixels.len())
+            .filter(|x| !ground.pixels[*x])
+            .collect();
+        assert_eq!(holes, vec![55, 56, 57]);
+    }
+}
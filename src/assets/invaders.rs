@@ -1,18 +1,27 @@
 //!
 
-use rand::{
-    distributions::{Distribution, Uniform},
-    Rng,
-};
-use tui::style::Color;
-use tui::widgets::canvas::{Painter, Shape};
+use std::sync::OnceLock;
 
+use tui::widgets::canvas::Painter;
+
+use crate::levels::Level;
 use crate::points;
+use crate::theme::Role;
 
-use super::{Area, Bunkers, Cannon, Laser};
+use super::{Area, Bitmap, Bunkers, Cannon, Laser, LaserKind, Palette, Sprite};
 
 const INVADERS_PER_ROW: usize = 8;
 
+/// The number of grid moves between each kind of bomb being fired, in the same order as
+/// [`LaserKind::BOMBS`].
+const BOMB_RELOAD: [u8; 3] = [24, 20, 28];
+
+/// The columns plunger bombs are fired from, in order. Adapted from the arcade's 11 column table.
+const PLUNGER_COLUMNS: [usize; 16] = [0, 4, 0, 0, 0, 2, 7, 0, 3, 1, 0, 0, 7, 5, 1, 5];
+
+/// The columns squiggly bombs are fired from, in order. Adapted from the arcade's 11 column table.
+const SQUIGGLY_COLUMNS: [usize; 15] = [7, 0, 3, 1, 0, 0, 7, 5, 1, 5, 1, 7, 2, 4, 6];
+
 ///
 #[derive(Clone, Debug, PartialEq)]
 pub struct InvaderGrid {
@@ -20,13 +29,18 @@ pub struct InvaderGrid {
     left: f64,
     bottom: f64,
     direction: InvaderDirection,
-    between: Uniform<usize>,
+    reload: [u8; 3],
+    reload_max: [u8; 3],
+    next_bomb: usize,
+    plunger_index: usize,
+    squiggly_index: usize,
 }
 
 impl InvaderGrid {
-    ///
-    pub fn new(level: u8) -> Self {
-        let level_offset = level as f64 * points::INVADER_MOVE_Y * -1.0;
+    /// Create the grid for a wave, starting `start_rows` moves down from the top.
+    pub fn new(level: &Level, start_rows: u8) -> Self {
+        let level_offset = -(start_rows as f64 * points::INVADER_MOVE_Y);
+        let reload_max = BOMB_RELOAD.map(|reload| Level::scale(reload, level.reload_percent));
         let mut rows = Vec::new();
         let invader_types = vec![
             InvaderType::Octopus,
@@ -50,7 +64,11 @@ impl InvaderGrid {
             left: points::GRID_INITIAL_X,
             bottom: points::GRID_INITIAL_Y + level_offset,
             direction: InvaderDirection::default(),
-            between: Uniform::from(0..INVADERS_PER_ROW),
+            reload: reload_max,
+            reload_max,
+            next_bomb: 0,
+            plunger_index: 0,
+            squiggly_index: 0,
         }
     }
 
@@ -70,7 +88,7 @@ impl InvaderGrid {
             self.bottom -= points::INVADER_MOVE_Y;
         } else {
             self.left += match self.direction {
-                InvaderDirection::Left => -1.0 * points::INVADER_MOVE_X,
+                InvaderDirection::Left => -points::INVADER_MOVE_X,
                 InvaderDirection::Right => points::INVADER_MOVE_X,
             };
         }
@@ -82,13 +100,18 @@ impl InvaderGrid {
                 row.move_along(self.direction, points::INVADER_MOVE_X);
             }
         }
+
+        for reload in self.reload.iter_mut() {
+            *reload = reload.saturating_sub(1);
+        }
     }
 
-    pub fn collides_with_laser(&mut self, laser: &Laser) -> Option<u32> {
+    /// Check whether a laser hits an invader, removing and returning the invader if so.
+    pub fn collides_with_laser(&mut self, laser: &Laser) -> Option<Invader> {
         if self.area().overlaps(laser.area()) {
             for row in self.rows.iter_mut() {
-                if let Some(score) = row.collides_with_laser(laser) {
-                    return Some(score);
+                if let Some(invader) = row.collides_with_laser(laser) {
+                    return Some(invader);
                 }
             }
         }
@@ -96,19 +119,6 @@ impl InvaderGrid {
         None
     }
 
-    ///
-    pub fn collides_with_cannon(&self, cannon: &Cannon) -> bool {
-        if self.area().overlaps(cannon.area()) {
-            for row in self.rows.iter() {
-                if row.collides_with_cannon(cannon) {
-                    return true;
-                }
-            }
-        }
-
-        false
-    }
-
     ///
     pub fn collides_with_bunkers(&self, bunkers: &Bunkers) -> Vec<&Invader> {
         let mut invaders = vec![];
@@ -122,19 +132,10 @@ impl InvaderGrid {
         invaders
     }
 
-    ///
-    pub fn is_visible(&self) -> bool {
-        if self.bottom > 0.0 {
-            return true;
-        }
-
-        for row in self.rows.iter() {
-            if !row.is_visible() {
-                return false;
-            }
-        }
-
-        true
+    /// Check whether any invader has reached the invasion line at the top of the cannon's row,
+    /// which ends the game wherever the cannon is.
+    pub fn has_invaded(&self) -> bool {
+        self.rows.iter().any(InvaderRow::has_invaded)
     }
 
     ///
@@ -179,36 +180,112 @@ impl InvaderGrid {
         true
     }
 
-    ///
-    pub fn laser<R: Rng + Sized>(&self, rng: &mut R) -> Option<Laser> {
+    /// Fire the next bomb whose reload timer has expired. The bomb kinds take turns, with rolling
+    /// bombs targeting the cannon and the others following their column tables.
+    pub fn laser(&mut self, cannon: &Cannon) -> Option<Laser> {
         if self.is_empty() {
             return None;
         }
 
-        let column = self.between.sample(rng);
+        for offset in 0..LaserKind::BOMBS.len() {
+            let index = (self.next_bomb + offset) % LaserKind::BOMBS.len();
 
-        for row in self.rows.iter() {
-            if row.is_empty() {
+            if self.reload[index] > 0 {
                 continue;
             }
 
-            if let Some(invader) = row.get(column) {
+            let kind = LaserKind::BOMBS[index];
+            let column = match kind {
+                LaserKind::Plunger => {
+                    self.table_column(&PLUNGER_COLUMNS, self.plunger_index)
+                        .map(|(column, next)| {
+                            self.plunger_index = next;
+                            column
+                        })
+                }
+                LaserKind::Squiggly => self
+                    .table_column(&SQUIGGLY_COLUMNS, self.squiggly_index)
+                    .map(|(column, next)| {
+                        self.squiggly_index = next;
+                        column
+                    }),
+                _ => self.column_above(cannon),
+            };
+
+            if let Some(invader) = column.and_then(|column| self.lowest_in_column(column).copied())
+            {
+                self.reload[index] = self.reload_max[index];
+                self.next_bomb = (index + 1) % LaserKind::BOMBS.len();
+
                 return Some(Laser::new_invader(
                     invader.left,
                     invader.bottom,
                     invader.invader_type,
+                    kind,
                 ));
             }
         }
 
         None
     }
+
+    /// Find the horizontal centre of the invader closest to `x`, for something to aim at.
+    pub fn nearest_invader_x(&self, x: f64) -> Option<f64> {
+        self.rows
+            .iter()
+            .flat_map(|row| row.invaders.iter().flatten())
+            .map(|invader| {
+                let area = invader.area();
+                (area.left + area.right) / 2.0
+            })
+            .min_by(|a, b| (a - x).abs().total_cmp(&(b - x).abs()))
+    }
+
+    /// Find the column directly above the centre of the cannon, if there is one.
+    fn column_above(&self, cannon: &Cannon) -> Option<usize> {
+        let cannon_centre = cannon.left + (points::CANNON_WIDTH / 2.0);
+        let column = ((cannon_centre - self.left)
+            / (points::ALIEN_WIDTH + points::ALIEN_BUFFER_WIDTH))
+            .floor();
+
+        if column >= 0.0 && (column as usize) < INVADERS_PER_ROW {
+            Some(column as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Find the next column in a table that still contains an invader, starting from `index`.
+    /// Returns the column and the index to start from next time.
+    fn table_column(&self, table: &[usize], index: usize) -> Option<(usize, usize)> {
+        for offset in 0..table.len() {
+            let index = (index + offset) % table.len();
+            let column = table[index];
+
+            if self.lowest_in_column(column).is_some() {
+                return Some((column, (index + 1) % table.len()));
+            }
+        }
+
+        None
+    }
+
+    ///
+    fn lowest_in_column(&self, column: usize) -> Option<&Invader> {
+        for row in self.rows.iter() {
+            if let Some(invader) = row.get(column) {
+                return Some(invader);
+            }
+        }
+
+        None
+    }
 }
 
-impl Shape for InvaderGrid {
-    fn draw(&self, painter: &mut Painter) {
+impl Sprite for InvaderGrid {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
         for row in self.rows.iter() {
-            row.draw(painter);
+            row.paint(painter, palette);
         }
     }
 }
@@ -260,7 +337,7 @@ impl InvaderRow {
     ///
     pub fn move_along(&mut self, direction: InvaderDirection, movement: f64) {
         self.left += match direction {
-            InvaderDirection::Left => -1.0 * movement,
+            InvaderDirection::Left => -movement,
             InvaderDirection::Right => movement,
         };
 
@@ -279,13 +356,13 @@ impl InvaderRow {
     }
 
     ///
-    pub fn collides_with_laser(&mut self, laser: &Laser) -> Option<u32> {
+    pub fn collides_with_laser(&mut self, laser: &Laser) -> Option<Invader> {
         if self.area().overlaps(laser.area()) {
             for (i, invader) in self.invaders.iter_mut().enumerate() {
-                if let Some(invader) = invader {
-                    if let Some(score) = invader.collides_with_laser(laser) {
+                if let Some(invader) = *invader {
+                    if invader.collides_with_laser(laser) {
                         self.delete(i);
-                        return Some(score);
+                        return Some(invader);
                     }
                 }
             }
@@ -294,19 +371,6 @@ impl InvaderRow {
         None
     }
 
-    ///
-    pub fn collides_with_cannon(&self, cannon: &Cannon) -> bool {
-        if self.area().overlaps(cannon.area()) {
-            for invader in self.invaders.iter().flatten() {
-                if invader.collides_with_cannon(cannon) {
-                    return true;
-                }
-            }
-        }
-
-        false
-    }
-
     ///
     pub fn collides_with_bunkers(&self, bunkers: &Bunkers) -> Vec<&Invader> {
         let bunkers_area = bunkers.area();
@@ -351,9 +415,9 @@ impl InvaderRow {
         self.count == 0
     }
 
-    ///
-    pub fn is_visible(&self) -> bool {
-        self.bottom > 0.0 || self.is_empty()
+    /// Rows that have been cleared can't invade, however low they are.
+    pub fn has_invaded(&self) -> bool {
+        !self.is_empty() && self.bottom <= points::INVASION_Y
     }
 
     ///
@@ -362,10 +426,10 @@ impl InvaderRow {
     }
 }
 
-impl Shape for InvaderRow {
-    fn draw(&self, painter: &mut Painter) {
+impl Sprite for InvaderRow {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
         for invader in self.invaders.iter().flatten() {
-            invader.draw(painter);
+            invader.paint(painter, palette);
         }
     }
 }
@@ -427,7 +491,7 @@ impl Invader {
     ///
     pub fn move_along(&mut self, direction: InvaderDirection, movement: f64) {
         self.left += match direction {
-            InvaderDirection::Left => -1.0 * movement,
+            InvaderDirection::Left => -movement,
             InvaderDirection::Right => movement,
         };
 
@@ -440,18 +504,30 @@ impl Invader {
         self.animation = self.animation.switch();
     }
 
-    ///
-    pub fn collides_with_laser(&self, laser: &Laser) -> Option<u32> {
-        if self.area().overlaps(laser.area()) {
-            Some(self.invader_type.score())
-        } else {
-            None
+    /// Check whether a laser has hit the invader itself, rather than just passed through a gap in
+    /// its sprite.
+    pub fn collides_with_laser(&self, laser: &Laser) -> bool {
+        let area = self.area();
+
+        if !area.overlaps(laser.area()) {
+            return false;
         }
+
+        let mask = self.mask();
+
+        laser
+            .points()
+            .any(|(x, y)| mask.hits(x - area.left, y - area.bottom))
     }
 
     ///
-    pub fn collides_with_cannon(&self, cannon: &Cannon) -> bool {
-        self.area().overlaps(cannon.area())
+    pub fn invader_type(&self) -> InvaderType {
+        self.invader_type
+    }
+
+    ///
+    pub fn score(&self) -> u32 {
+        self.invader_type.score()
     }
 
     ///
@@ -466,23 +542,54 @@ impl Invader {
         )
     }
 
+    /// The collision mask for the invader's current frame. Masks are built from the sprites the
+    /// first time they're needed.
+    fn mask(&self) -> &'static Bitmap {
+        static MASKS: OnceLock<[[Bitmap; 2]; 3]> = OnceLock::new();
+
+        let masks = MASKS.get_or_init(|| {
+            [InvaderType::Crab, InvaderType::Squid, InvaderType::Octopus].map(|invader_type| {
+                [InvaderAnimation::Original, InvaderAnimation::Alternate].map(|animation| {
+                    let invader = Invader {
+                        invader_type,
+                        animation,
+                        left: 0.0,
+                        bottom: 0.0,
+                    };
+
+                    Bitmap::from_points(invader.width(), points::ALIEN_HEIGHT, invader.data())
+                })
+            })
+        });
+
+        let [crab, squid, octopus] = masks;
+        let [original, alternate] = match self.invader_type {
+            InvaderType::Crab => crab,
+            InvaderType::Squid => squid,
+            InvaderType::Octopus => octopus,
+        };
+
+        match self.animation {
+            InvaderAnimation::Original => original,
+            InvaderAnimation::Alternate => alternate,
+        }
+    }
+
     fn draw_x_offset(&self) -> f64 {
         ((points::ALIEN_WIDTH - self.width()) / 2.0).floor()
     }
 }
 
-impl Shape for Invader {
-    fn draw(&self, painter: &mut Painter) {
+impl Sprite for Invader {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
         let x_offset = self.draw_x_offset();
-        let color = self.invader_type.color();
+        let role = Role::Invader(self.invader_type);
 
         for (x, y) in self.data() {
             let x = x + self.left + x_offset;
             let y = y + self.bottom;
 
-            if let Some((x, y)) = painter.get_point(x, y) {
-                painter.paint(x, y, color);
-            }
+            super::paint_point(painter, palette, x, y, role);
         }
     }
 }
@@ -495,15 +602,6 @@ pub enum InvaderType {
 }
 
 impl InvaderType {
-    ///
-    pub fn color(&self) -> Color {
-        match self {
-            Self::Crab => Color::White,
-            Self::Squid => Color::Yellow,
-            Self::Octopus => Color::Cyan,
-        }
-    }
-
     ///
     pub fn score(&self) -> u32 {
         match self {
@@ -562,3 +660,131 @@ impl Default for InvaderDirection {
         Self::Right
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::levels;
+
+    #[test]
+    fn test_rolling_bomb_targets_cannon() {
+        let mut grid = InvaderGrid::new(&Level::for_wave(0, 1), 0);
+        grid.reload = [0; 3];
+
+        let mut cannon = Cannon::new_normal();
+        for _ in 0..20 {
+            cannon.move_right();
+        }
+
+        let column = grid.column_above(&cannon).unwrap();
+        let invader = *grid.lowest_in_column(column).unwrap();
+        let laser = grid.laser(&cannon).unwrap();
+
+        assert_eq!(column, 2);
+        assert_eq!(
+            laser,
+            Laser::new_invader(
+                invader.left,
+                invader.bottom,
+                invader.invader_type,
+                LaserKind::Rolling
+            )
+        );
+    }
+
+    #[test]
+    fn test_mask_matches_sprite() {
+        for invader_type in [InvaderType::Crab, InvaderType::Squid, InvaderType::Octopus] {
+            let mut invader = Invader::new(invader_type, 0.0, 0.0);
+
+            for _ in 0..2 {
+                let sprite =
+                    Bitmap::from_points(invader.width(), points::ALIEN_HEIGHT, invader.data());
+                assert_eq!(*invader.mask(), sprite);
+
+                invader.animation = invader.animation.switch();
+            }
+        }
+    }
+
+    #[test]
+    fn test_laser_misses_gaps_in_sprite() {
+        // lasers move two points at a time from an odd height, so line the invader up with them
+        let invader = Invader::new(InvaderType::Crab, 0.0, 21.0);
+        let area = invader.area();
+        let mask = invader.mask();
+
+        // the cannon laser is a single column of two points
+        let laser_at = |x: i32, y: i32| {
+            let mut laser =
+                Laser::new_cannon(area.left + x as f64 - points::CANNON_LASER_INITIAL_X_OFFSET);
+
+            while laser.area().bottom < area.bottom + y as f64 {
+                laser.on_tick();
+            }
+
+            laser
+        };
+
+        for x in 0..points::CRAB_WIDTH as i32 {
+            for y in (0..points::ALIEN_HEIGHT as i32 - 1).step_by(2) {
+                let laser = laser_at(x, y);
+                let hit = mask.get(x, y) || mask.get(x, y + 1);
+
+                assert!(area.overlaps(laser.area()));
+                assert_eq!(invader.collides_with_laser(&laser), hit, "({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_column_skips_empty_columns() {
+        let mut grid = InvaderGrid::new(&Level::for_wave(0, 1), 0);
+
+        for row in grid.rows.iter_mut() {
+            row.delete(0);
+        }
+
+        assert_eq!(grid.table_column(&PLUNGER_COLUMNS, 0), Some((4, 2)));
+        assert_eq!(grid.table_column(&SQUIGGLY_COLUMNS, 1), Some((3, 3)));
+    }
+
+    #[test]
+    fn test_nearest_invader_skips_empty_columns() {
+        let mut grid = InvaderGrid::new(&Level::for_wave(0, 1), 0);
+        let column_left = points::GRID_INITIAL_X + points::ALIEN_WIDTH + points::ALIEN_BUFFER_WIDTH;
+
+        for row in grid.rows.iter_mut() {
+            row.delete(0);
+        }
+
+        let x = grid.nearest_invader_x(0.0).unwrap();
+        assert!(x > column_left && x < column_left + points::ALIEN_WIDTH);
+    }
+
+    #[test]
+    fn test_lowest_start_has_not_invaded() {
+        let grid = InvaderGrid::new(&Level::for_wave(0, 1), levels::MAX_START_ROWS);
+        assert!(!grid.has_invaded());
+
+        let grid = InvaderGrid::new(&Level::for_wave(0, 1), levels::MAX_START_ROWS + 1);
+        assert!(grid.has_invaded());
+    }
+
+    #[test]
+    fn test_cleared_rows_cannot_invade() {
+        let mut grid = InvaderGrid::new(&Level::for_wave(0, 1), 0);
+
+        while !grid.has_invaded() {
+            grid.move_along();
+        }
+
+        assert!(grid.rows[0].bottom <= points::INVASION_Y);
+
+        for i in 0..INVADERS_PER_ROW {
+            grid.rows[0].delete(i);
+        }
+
+        assert!(!grid.has_invaded());
+    }
+}
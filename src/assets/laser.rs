@@ -1,19 +1,20 @@
 //!
 
-use tui::style::Color;
-use tui::widgets::canvas::{Painter, Shape};
+use tui::widgets::canvas::Painter;
 
 use crate::points;
+use crate::theme::Role;
 
-use super::{Area, InvaderType};
+use super::{Area, InvaderType, Palette, Sprite};
 
 ///
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Laser {
     left: f64,
     bottom: f64,
-    color: Color,
-    direction: Direction,
+    role: Role,
+    kind: LaserKind,
+    frame: usize,
 }
 
 impl Laser {
@@ -22,32 +23,42 @@ impl Laser {
         Self {
             left: cannon_left + points::CANNON_LASER_INITIAL_X_OFFSET,
             bottom: points::CANNON_LASER_INITIAL_Y,
-            color: Color::Green,
-            direction: Direction::Up,
+            role: Role::Cannon,
+            kind: LaserKind::Cannon,
+            frame: 0,
         }
     }
 
     ///
-    pub fn new_invader(invader_left: f64, invader_bottom: f64, invader_type: InvaderType) -> Self {
+    pub fn new_invader(
+        invader_left: f64,
+        invader_bottom: f64,
+        invader_type: InvaderType,
+        kind: LaserKind,
+    ) -> Self {
         Self {
-            left: invader_left + (points::ALIEN_WIDTH / 2.0).floor(),
-            bottom: invader_bottom - points::LASER_HEIGHT,
-            color: invader_type.color(),
-            direction: Direction::Down,
+            left: invader_left + (points::ALIEN_WIDTH / 2.0).floor() - (kind.width() / 2.0).floor(),
+            bottom: invader_bottom - kind.height(),
+            role: Role::Invader(invader_type),
+            kind,
+            frame: 0,
         }
     }
 
+    ///
+    pub fn kind(&self) -> LaserKind {
+        self.kind
+    }
+
     ///
     pub fn on_tick(&mut self) {
-        self.bottom += match self.direction {
-            Direction::Up => points::LASER_MOVE,
-            Direction::Down => -1.0 * points::LASER_MOVE,
-        };
+        self.bottom += self.kind.movement();
+        self.frame = (self.frame + 1) % points::BOMB_FRAMES;
     }
 
     ///
     pub fn is_visible(&self) -> bool {
-        self.bottom >= 0.0 && (self.bottom + points::LASER_HEIGHT) <= points::GAME_HEIGHT
+        self.bottom >= 0.0 && (self.bottom + self.kind.height()) <= points::GAME_HEIGHT
     }
 
     ///
@@ -55,38 +66,97 @@ impl Laser {
         Area::new(
             self.left,
             self.bottom,
-            self.left + points::LASER_WIDTH,
-            self.bottom + points::LASER_HEIGHT,
+            self.left + self.kind.width(),
+            self.bottom + self.kind.height(),
         )
     }
 
     ///
-    pub fn bunker_collision_area(&self) -> Area {
-        Area::new(
-            self.left - 1.0,
-            self.bottom,
-            self.left + points::LASER_WIDTH + 1.0,
-            self.bottom + points::LASER_HEIGHT,
-        )
+    pub fn collides_with_laser(&self, other: &Laser) -> bool {
+        self.area().overlaps(other.area())
     }
-}
 
-impl Shape for Laser {
-    fn draw(&self, painter: &mut Painter) {
-        for (x, y) in &points::LASER {
-            let x = x + self.left;
-            let y = y + self.bottom;
+    ///
+    pub fn data(&self) -> &'static [(f64, f64)] {
+        match self.kind {
+            LaserKind::Cannon => &points::LASER,
+            LaserKind::Rolling => &points::ROLLING[self.frame],
+            LaserKind::Plunger => &points::PLUNGER[self.frame],
+            LaserKind::Squiggly => &points::SQUIGGLY[self.frame],
+        }
+    }
+
+    /// The points the laser covers on the playfield.
+    pub fn points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.data()
+            .iter()
+            .map(|(x, y)| (x + self.left, y + self.bottom))
+    }
+}
 
-            if let Some((x, y)) = painter.get_point(x, y) {
-                painter.paint(x, y, self.color);
-            }
+impl Sprite for Laser {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
+        for (x, y) in self.points() {
+            super::paint_point(painter, palette, x, y, self.role);
         }
     }
 }
 
-///
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum Direction {
-    Up,
-    Down,
+/// The kinds of laser that can be fired. The cannon fires a single kind of laser, while the
+/// invaders take turns firing the three kinds of bomb from the arcade original.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LaserKind {
+    Cannon,
+    Rolling,
+    Plunger,
+    Squiggly,
+}
+
+impl LaserKind {
+    /// The kinds of laser that can be fired by the invaders, in the order they take turns.
+    pub const BOMBS: [Self; 3] = [Self::Rolling, Self::Plunger, Self::Squiggly];
+
+    /// The vertical distance the laser travels each tick.
+    pub fn movement(&self) -> f64 {
+        match self {
+            Self::Cannon => points::LASER_MOVE,
+            Self::Rolling => -points::ROLLING_MOVE,
+            Self::Plunger => -points::PLUNGER_MOVE,
+            Self::Squiggly => -points::SQUIGGLY_MOVE,
+        }
+    }
+
+    ///
+    pub fn width(&self) -> f64 {
+        match self {
+            Self::Cannon => points::LASER_WIDTH,
+            _ => points::BOMB_WIDTH,
+        }
+    }
+
+    ///
+    pub fn height(&self) -> f64 {
+        match self {
+            Self::Cannon => points::LASER_HEIGHT,
+            _ => points::BOMB_HEIGHT,
+        }
+    }
+
+    /// The ragged shape knocked out of a bunker where the laser hits it.
+    pub fn bunker_mask(&self) -> &'static [(f64, f64)] {
+        match self {
+            Self::Cannon => &points::CANNON_LASER_MASK,
+            Self::Rolling => &points::ROLLING_MASK,
+            Self::Plunger => &points::PLUNGER_MASK,
+            Self::Squiggly => &points::SQUIGGLY_MASK,
+        }
+    }
+
+    ///
+    pub fn bunker_mask_width(&self) -> f64 {
+        match self {
+            Self::Cannon => points::CANNON_LASER_MASK_WIDTH,
+            _ => points::BOMB_MASK_WIDTH,
+        }
+    }
 }
@@ -1,17 +1,136 @@
 //!
 
 mod area;
+mod bitmap;
 mod bunker;
 mod cannon;
+mod explosion;
+mod ground;
 mod invaders;
 mod laser;
 mod mystery_ship;
 mod words;
 
+use tui::style::Color;
+use tui::widgets::canvas::{Painter, Shape};
+
+use crate::points;
+use crate::theme::{Role, Theme};
+
 use area::Area;
-pub use bunker::{Bunker, Bunkers};
+use bitmap::Bitmap;
+pub use bunker::{BunkerShape, Bunkers};
 pub use cannon::Cannon;
+pub use explosion::Explosion;
+pub use ground::Ground;
 pub use invaders::{Invader, InvaderGrid, InvaderType};
-pub use laser::Laser;
+pub use laser::{Laser, LaserKind};
 pub use mystery_ship::MysteryShip;
 pub use words::Words;
+
+/// Something that can be drawn on a canvas, in the colours of a theme.
+pub trait Sprite {
+    ///
+    fn paint(&self, painter: &mut Painter, palette: &Palette);
+}
+
+/// Chooses the colour of each point painted by a sprite.
+#[derive(Clone, Copy, Debug)]
+pub struct Palette<'a> {
+    theme: &'a Theme,
+    /// The top of the bottom strip of the cellophane overlay, if the overlay is on.
+    cellophane: Option<f64>,
+}
+
+impl<'a> Palette<'a> {
+    /// The colour of a point, by the role of the sprite it belongs to or, with the cellophane
+    /// overlay, by how far up the playfield it is.
+    pub fn color(&self, role: Role, y: f64) -> Color {
+        let Some(bottom_strip_top) = self.cellophane else {
+            return self.theme.color(role);
+        };
+
+        if y >= points::CELLOPHANE_TOP_Y {
+            self.theme.mystery_ship
+        } else if y < bottom_strip_top {
+            self.theme.cannon
+        } else {
+            self.theme.text
+        }
+    }
+}
+
+/// A sprite paired with the theme to draw it in, so it can be drawn on a canvas.
+pub struct Themed<'a, S: Sprite> {
+    sprite: &'a S,
+    palette: Palette<'a>,
+}
+
+impl<'a, S: Sprite> Themed<'a, S> {
+    ///
+    pub fn new(sprite: &'a S, theme: &'a Theme) -> Self {
+        Self {
+            sprite,
+            palette: Palette {
+                theme,
+                cellophane: None,
+            },
+        }
+    }
+
+    /// Colour the sprite by the strips of the cellophane overlay it is under, where the bottom strip
+    /// reaches up to `bottom_strip_top`. This only makes sense for sprites drawn on the playfield.
+    pub fn cellophane(mut self, bottom_strip_top: Option<f64>) -> Self {
+        self.palette.cellophane = bottom_strip_top;
+        self
+    }
+}
+
+impl<'a, S: Sprite> Shape for Themed<'a, S> {
+    fn draw(&self, painter: &mut Painter) {
+        self.sprite.paint(painter, &self.palette);
+    }
+}
+
+/// Paint a point, filling all the dots it covers so that sprites stay solid when the canvas is
+/// scaled up.
+fn paint_point(painter: &mut Painter, palette: &Palette, x: f64, y: f64, role: Role) {
+    let color = palette.color(role, y);
+
+    if let Some((left, bottom)) = painter.get_point(x, y) {
+        // the opposite corner is off the canvas for points along the top and right edges
+        let (right, top) = painter
+            .get_point(x + 1.0, y + 1.0)
+            .unwrap_or((left, bottom));
+
+        for x in left..right.max(left + 1) {
+            for y in (top + 1).min(bottom)..=bottom {
+                painter.paint(x, y, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cellophane_colors() {
+        let theme = Theme::classic();
+        let palette = Palette {
+            theme: &theme,
+            cellophane: None,
+        };
+        assert_eq!(palette.color(Role::Cannon, 120.0), Color::Green);
+
+        let palette = Palette {
+            cellophane: Some(30.0),
+            ..palette
+        };
+        let role = Role::Invader(InvaderType::Squid);
+        assert_eq!(palette.color(role, points::CELLOPHANE_TOP_Y), Color::Red);
+        assert_eq!(palette.color(role, 30.0), Color::White);
+        assert_eq!(palette.color(role, 29.0), Color::Green);
+    }
+}
@@ -1,11 +1,11 @@
 //!
 
-use tui::style::Color;
-use tui::widgets::canvas::{Painter, Shape};
+use tui::widgets::canvas::Painter;
 
 use crate::points;
+use crate::theme::Role;
 
-use super::{Area, Laser};
+use super::{Area, Laser, Palette, Sprite};
 
 ///
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -23,6 +23,14 @@ impl MysteryShip {
         }
     }
 
+    /// Create a mystery ship at a fixed position, for drawing outside of a game.
+    pub fn new_at(left: f64, bottom: f64) -> Self {
+        Self {
+            left,
+            right: bottom,
+        }
+    }
+
     /// Reset the location of the myster ship to the far right of the game window.
     pub fn reset(&mut self) {
         self.left = points::MYSTERY_SHIP_INITIAL_X;
@@ -79,15 +87,13 @@ impl MysteryShip {
     }
 }
 
-impl Shape for MysteryShip {
-    fn draw(&self, painter: &mut Painter) {
+impl Sprite for MysteryShip {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
         for (x, y) in &points::MYSTERY_SHIP {
             let x = x + self.left;
             let y = y + self.right;
 
-            if let Some((x, y)) = painter.get_point(x, y) {
-                painter.paint(x, y, Color::Red);
-            }
+            super::paint_point(painter, palette, x, y, Role::MysteryShip);
         }
     }
 }
@@ -1,9 +1,11 @@
 //!
 
-use tui::style::Color;
-use tui::widgets::canvas::{Painter, Shape};
+use tui::widgets::canvas::Painter;
 
 use crate::points::letters;
+use crate::theme::Role;
+
+use super::{Palette, Sprite};
 
 const ROW_HEIGHT: f64 = letters::LETTER_HEIGHT + letters::LETTER_SPACING_Y;
 
@@ -11,7 +13,7 @@ const ROW_HEIGHT: f64 = letters::LETTER_HEIGHT + letters::LETTER_SPACING_Y;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Words {
     rows: Vec<Row>,
-    color: Color,
+    role: Role,
 }
 
 impl Words {
@@ -22,7 +24,23 @@ impl Words {
                 Row::new(vec![Letter::O, Letter::V, Letter::E, Letter::R]),
                 Row::new(vec![Letter::G, Letter::A, Letter::M, Letter::E]),
             ],
-            color: Color::Red,
+            role: Role::GameOver,
+        }
+    }
+
+    /// Shown instead of "game over" when the invaders reach the cannon.
+    pub fn invaded() -> Self {
+        Self {
+            rows: vec![Row::new(vec![
+                Letter::I,
+                Letter::N,
+                Letter::V,
+                Letter::A,
+                Letter::D,
+                Letter::E,
+                Letter::D,
+            ])],
+            role: Role::GameOver,
         }
     }
 
@@ -42,7 +60,26 @@ impl Words {
                 ]),
                 Row::new(vec![Letter::S, Letter::P, Letter::A, Letter::C, Letter::E]),
             ],
-            color: Color::Yellow,
+            role: Role::Title,
+        }
+    }
+
+    /// The wave number, shown before each wave starts.
+    pub fn wave(wave: u32) -> Self {
+        Self {
+            rows: vec![
+                Row::number(wave),
+                Row::new(vec![Letter::W, Letter::A, Letter::V, Letter::E]),
+            ],
+            role: Role::Title,
+        }
+    }
+
+    ///
+    pub fn number(number: u32, role: Role) -> Self {
+        Self {
+            rows: vec![Row::number(number)],
+            role,
         }
     }
 
@@ -70,12 +107,18 @@ impl Words {
     }
 }
 
-impl Shape for Words {
-    fn draw(&self, painter: &mut Painter) {
+impl Sprite for Words {
+    fn paint(&self, painter: &mut Painter, palette: &Palette) {
         let max_width = self.width();
 
         for (i, row) in self.rows.iter().enumerate() {
-            row.draw(painter, max_width, i as f64 * ROW_HEIGHT, self.color);
+            row.draw(
+                painter,
+                palette,
+                max_width,
+                i as f64 * ROW_HEIGHT,
+                self.role,
+            );
         }
     }
 }
@@ -92,6 +135,17 @@ impl Row {
         Self { letters }
     }
 
+    ///
+    pub fn number(number: u32) -> Self {
+        let letters = number
+            .to_string()
+            .bytes()
+            .map(|digit| Letter::Digit(digit - b'0'))
+            .collect();
+
+        Self { letters }
+    }
+
     ///
     fn width(&self) -> f64 {
         let mut width = (self.letters.len() - 1) as f64 * letters::LETTER_SPACING_X;
@@ -103,7 +157,14 @@ impl Row {
         width
     }
 
-    pub fn draw(&self, painter: &mut Painter, row_width: f64, y_offset: f64, color: Color) {
+    pub fn draw(
+        &self,
+        painter: &mut Painter,
+        palette: &Palette,
+        row_width: f64,
+        y_offset: f64,
+        role: Role,
+    ) {
         let row_x_offset = (row_width - self.width()) / 2.0;
         let mut letter_x_offset = 0.0;
 
@@ -112,9 +173,7 @@ impl Row {
                 let x = x + letter_x_offset + row_x_offset;
                 let y = y + y_offset;
 
-                if let Some((x, y)) = painter.get_point(x, y) {
-                    painter.paint(x, y, color);
-                }
+                super::paint_point(painter, palette, x, y, role);
             }
 
             letter_x_offset += letter.width() + letters::LETTER_SPACING_X;
@@ -138,6 +197,8 @@ enum Letter {
     R,
     S,
     V,
+    W,
+    Digit(u8),
 }
 
 impl Letter {
@@ -157,6 +218,19 @@ impl Letter {
             Self::R => &letters::R,
             Self::S => &letters::S,
             Self::V => &letters::V,
+            Self::W => &letters::W,
+            Self::Digit(digit) => match digit {
+                0 => &letters::ZERO,
+                1 => &letters::ONE,
+                2 => &letters::TWO,
+                3 => &letters::THREE,
+                4 => &letters::FOUR,
+                5 => &letters::FIVE,
+                6 => &letters::SIX,
+                7 => &letters::SEVEN,
+                8 => &letters::EIGHT,
+                _ => &letters::NINE,
+            },
         }
     }
 
@@ -176,6 +250,8 @@ impl Letter {
             Self::R => letters::R_WIDTH,
             Self::S => letters::S_WIDTH,
             Self::V => letters::V_WIDTH,
+            Self::W => letters::W_WIDTH,
+            Self::Digit(_) => letters::DIGIT_WIDTH,
         }
     }
 }
@@ -0,0 +1,150 @@
+//!
+
+use std::fmt;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::achievements::Achievement;
+use crate::assets::{InvaderType, LaserKind};
+
+/// Something that happened during a game. The app emits these as the game is played, so that
+/// anything interested in them can react without being wired into the simulation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameEvent {
+    /// A laser was fired, either by the cannon or an invader.
+    LaserFired {
+        kind: LaserKind,
+    },
+    /// The grid moved, playing the given note of the march.
+    GridMoved {
+        note: u8,
+    },
+    /// The mystery ship moved across the screen.
+    MysteryShipMoved,
+    InvaderKilled {
+        invader_type: InvaderType,
+        position: (f64, f64),
+        score: u32,
+    },
+    MysteryShipHit {
+        score: u32,
+    },
+    /// A cannon laser collided with an invader laser.
+    LasersCancelled {
+        position: (f64, f64),
+    },
+    BunkerDamaged {
+        position: (f64, f64),
+    },
+    LifeLost {
+        cause: DeathCause,
+    },
+    BonusLife {
+        lives: u8,
+    },
+    /// A wave was cleared, awarding a bonus.
+    WaveCleared {
+        wave: u32,
+        bonus: u32,
+    },
+    GameOver {
+        score: u32,
+        wave: u32,
+    },
+    AchievementUnlocked {
+        achievement: Achievement,
+    },
+}
+
+/// The reasons the cannon can be destroyed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeathCause {
+    /// Hit by a kind of invader bomb.
+    Bomb(LaserKind),
+    /// The invaders reached the cannon.
+    Invaded,
+}
+
+/// Something that reacts to game events.
+pub trait EventListener {
+    ///
+    fn on_event(&mut self, event: &GameEvent) -> io::Result<()>;
+
+    /// Whether the listener makes sounds, so should hear nothing while muted or during a demo.
+    fn is_audible(&self) -> bool {
+        false
+    }
+
+    /// Advance time by a single tick.
+    fn on_tick(&mut self, _tick_length: Duration) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Finish listening, flushing any output.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The listeners subscribed to an app's events.
+#[derive(Default)]
+pub struct Listeners(Vec<Box<dyn EventListener>>);
+
+impl Listeners {
+    ///
+    pub fn push(&mut self, listener: Box<dyn EventListener>) {
+        self.0.push(listener);
+    }
+
+    /// Send an event to every listener, leaving out those that make sounds if `audible` is false.
+    pub fn on_event(&mut self, event: &GameEvent, audible: bool) -> io::Result<()> {
+        for listener in self.0.iter_mut() {
+            if audible || !listener.is_audible() {
+                listener.on_event(event)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    pub fn on_tick(&mut self, tick_length: Duration) -> io::Result<()> {
+        self.0
+            .iter_mut()
+            .try_for_each(|listener| listener.on_tick(tick_length))
+    }
+
+    ///
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.0.iter_mut().try_for_each(|listener| listener.finish())
+    }
+}
+
+impl fmt::Debug for Listeners {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Listeners({})", self.0.len())
+    }
+}
+
+/// A listener that writes each event on its own line, for debugging.
+#[derive(Debug)]
+pub struct EventLog<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> EventLog<W> {
+    ///
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write> EventListener for EventLog<W> {
+    fn on_event(&mut self, event: &GameEvent) -> io::Result<()> {
+        writeln!(self.writer, "{event:?}")
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,178 @@
+//!
+
+use crate::points;
+
+/// The parameters that set the difficulty of each level. Once the last level is cleared, the
+/// table starts again from the first level with the difficulty raised by [`Level::for_wave`].
+pub const LEVELS: [Level; 7] = [
+    Level::new(100, 0, 100),
+    Level::new(95, 0, 95),
+    Level::new(90, 0, 90),
+    Level::new(85, 1, 85),
+    Level::new(80, 1, 80),
+    Level::new(75, 1, 75),
+    Level::new(70, 2, 70),
+];
+
+pub const MAX_LEVEL: u8 = (LEVELS.len() - 1) as u8;
+
+/// The number of times the grid has moved down when each wave starts, from the arcade original.
+/// Waves after the ninth start again from the top of the table.
+pub const CLASSIC_START_ROWS: [u8; 9] = [0, 3, 5, 6, 6, 6, 7, 7, 7];
+
+/// The furthest the grid can start moved down while leaving its bottom row above the invasion line.
+pub const MAX_START_ROWS: u8 =
+    ((points::GRID_INITIAL_Y - points::INVASION_Y - 1.0) / points::INVADER_MOVE_Y) as u8;
+
+/// How much harder each pass through the level table gets.
+const LOOP_PERCENT_STEP: u8 = 10;
+const LOOP_MIN_PERCENT: u8 = 25;
+const LOOP_MAX_EXTRA_LASERS: u8 = 6;
+
+/// The difficulty of a single wave.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Level {
+    /// The bomb reload time, as a percentage of the normal reload time.
+    pub reload_percent: u8,
+    /// The number of invader lasers allowed on top of the configured maximum.
+    pub extra_invader_lasers: u8,
+    /// The interval between mystery ships, as a percentage of the configured interval.
+    pub mystery_ship_percent: u8,
+}
+
+impl Level {
+    ///
+    const fn new(reload_percent: u8, extra_invader_lasers: u8, mystery_ship_percent: u8) -> Self {
+        Self {
+            reload_percent,
+            extra_invader_lasers,
+            mystery_ship_percent,
+        }
+    }
+
+    /// Get the difficulty of a wave, counting waves from 1. Waves after the end of the level
+    /// table wrap around to the start, but keep getting faster and firing more.
+    pub fn for_wave(start_level: u8, wave: u32) -> Self {
+        let index = wave_index(start_level, wave);
+        let level = LEVELS[(index % LEVELS.len() as u32) as usize];
+        let loops = (index / LEVELS.len() as u32).min(u8::MAX as u32) as u8;
+        let step = loops.saturating_mul(LOOP_PERCENT_STEP);
+
+        Self {
+            reload_percent: level
+                .reload_percent
+                .saturating_sub(step)
+                .max(LOOP_MIN_PERCENT),
+            extra_invader_lasers: level
+                .extra_invader_lasers
+                .saturating_add(loops)
+                .min(LOOP_MAX_EXTRA_LASERS),
+            mystery_ship_percent: level
+                .mystery_ship_percent
+                .saturating_sub(step)
+                .max(LOOP_MIN_PERCENT),
+        }
+    }
+
+    /// Scale a number of ticks or moves by a percentage, never going below 1.
+    pub fn scale<T: Into<u32> + TryFrom<u32>>(value: T, percent: u8) -> T {
+        let scaled = (value.into() * percent as u32 / 100).max(1);
+        T::try_from(scaled).unwrap_or_else(|_| unreachable!("scaling down cannot overflow"))
+    }
+}
+
+/// Get the number of times the grid has moved down when a wave starts, from a table of starting
+/// rows that wraps around like the level table. An empty table always starts at the top.
+pub fn start_rows(table: &[u8], start_level: u8, wave: u32) -> u8 {
+    if table.is_empty() {
+        return 0;
+    }
+
+    table[wave_index(start_level, wave) as usize % table.len()]
+}
+
+/// Count waves from the start of the tables, skipping ahead for the starting level.
+fn wave_index(start_level: u8, wave: u32) -> u32 {
+    start_level.min(MAX_LEVEL) as u32 + wave.saturating_sub(1)
+}
+
+/// Get the number of ticks between grid moves for the number of invaders remaining. The delay
+/// shrinks from `max_delay` with a full grid to no delay at all for the last invader, following
+/// `remaining / total` raised to the power of `curve`. A curve of 1 speeds up in proportion to the
+/// invaders remaining like the arcade original, while larger values speed up sooner.
+pub fn march_delay(max_delay: u8, curve: f64, remaining: usize, total: usize) -> u8 {
+    if remaining <= 1 || total == 0 {
+        return 0;
+    }
+
+    let fraction = (remaining as f64 / total as f64).min(1.0);
+    let delay = (max_delay as f64 * fraction.powf(curve.max(0.0))).round();
+
+    delay as u8
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_level_for_wave_wraps() {
+        assert_eq!(Level::for_wave(0, 1), LEVELS[0]);
+        assert_eq!(Level::for_wave(0, 7), LEVELS[6]);
+        assert_eq!(Level::for_wave(2, 1), LEVELS[2]);
+    }
+
+    #[test]
+    fn test_start_rows() {
+        assert_eq!(start_rows(&CLASSIC_START_ROWS, 0, 1), 0);
+        assert_eq!(start_rows(&CLASSIC_START_ROWS, 0, 2), 3);
+        assert_eq!(start_rows(&CLASSIC_START_ROWS, 0, 9), 7);
+        assert_eq!(start_rows(&CLASSIC_START_ROWS, 0, 10), 0);
+        assert_eq!(start_rows(&CLASSIC_START_ROWS, 2, 1), 5);
+        assert_eq!(start_rows(&[], 0, 5), 0);
+    }
+
+    #[test]
+    fn test_level_for_wave_gets_harder() {
+        let first = Level::for_wave(0, 1);
+        let second_loop = Level::for_wave(0, 8);
+        let third_loop = Level::for_wave(0, 15);
+
+        assert!(second_loop.reload_percent < first.reload_percent);
+        assert!(third_loop.reload_percent < second_loop.reload_percent);
+        assert!(second_loop.extra_invader_lasers > first.extra_invader_lasers);
+        assert!(second_loop.mystery_ship_percent < first.mystery_ship_percent);
+
+        let last = Level::for_wave(0, 1000);
+        assert_eq!(last.reload_percent, LOOP_MIN_PERCENT);
+        assert_eq!(last.extra_invader_lasers, LOOP_MAX_EXTRA_LASERS);
+    }
+
+    #[test]
+    fn test_march_delay() {
+        assert_eq!(march_delay(5, 1.0, 40, 40), 5);
+        assert_eq!(march_delay(5, 1.0, 20, 40), 3);
+        assert_eq!(march_delay(5, 1.0, 8, 40), 1);
+        assert_eq!(march_delay(5, 1.0, 1, 40), 0);
+        assert_eq!(march_delay(5, 2.0, 20, 40), 1);
+        assert_eq!(march_delay(5, 0.0, 2, 40), 5);
+    }
+
+    #[test]
+    fn test_march_delay_never_slows_down() {
+        for curve in [0.5, 1.0, 1.5, 3.0] {
+            for remaining in 1..40 {
+                assert!(
+                    march_delay(5, curve, remaining, 40)
+                        <= march_delay(5, curve, remaining + 1, 40)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_level_scale() {
+        assert_eq!(Level::scale(2000u16, 50), 1000);
+        assert_eq!(Level::scale(1u8, 25), 1);
+    }
+}
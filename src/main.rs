@@ -1,55 +1,321 @@
 //!
 
+#![allow(unknown_lints, clippy::empty_docs)]
+
+mod achievements;
 mod app;
 mod assets;
+mod events;
+mod levels;
+mod paths;
 mod points;
+mod replay;
+mod settings;
+mod sound;
+mod stats;
+mod theme;
 mod ui;
 
-// use std::env;
+use std::fs::File;
 use std::io;
-// use std::path::PathBuf;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use crossterm::{
-    event::{self, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+        Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use tui::{backend::CrosstermBackend, Terminal};
+use tui::{backend::CrosstermBackend, layout::Rect, Terminal};
+
+use achievements::Achievements;
+use events::EventLog;
+use settings::Settings;
+use sound::{BellSink, NullSink, PlayerSink, SoundEffects, SoundSink};
+use stats::LifetimeStats;
 
 /// A Space Invaders terminal game
 #[derive(Parser, Debug)]
 #[clap(version)]
 struct Args {
-    /// The interval in ticks between appearances of the mystery ship.
-    #[clap(long, default_value_t = 2000)]
-    mystery_ship_interval: u16,
+    /// Where to play sound effects
+    #[clap(long, value_enum, default_value_t = SoundBackend::Auto)]
+    sound: SoundBackend,
+
+    /// The command to stream sound effects to as raw 16 bit mono samples, when using the player
+    /// backend [default: aplay -q -t raw -f S16_LE -c 1 -r 22050]
+    #[clap(long, value_name = "COMMAND")]
+    sound_player: Option<String>,
+
+    /// How to draw the game, for terminals whose fonts can't show braille [default: braille]
+    #[clap(long, value_enum)]
+    renderer: Option<ui::Renderer>,
+
+    /// The file to render sound effects to, when using the WAV backend
+    #[cfg(feature = "wav")]
+    #[clap(long, default_value = "invaders.wav")]
+    sound_file: PathBuf,
+
+    /// Start with sound effects muted
+    #[clap(long)]
+    mute: bool,
+
+    /// Start with the playfield zoomed by whole steps to keep sprites sharp
+    #[clap(long)]
+    zoom: bool,
+
+    /// Write every game event to a file, for debugging
+    #[clap(long)]
+    event_log: Option<PathBuf>,
+
+    /// The path of the config file [default: $XDG_CONFIG_HOME/invaders/config.toml]
+    #[clap(long)]
+    config: Option<PathBuf>,
+
+    /// The number of lives to start with [default: 3]
+    #[clap(long, value_parser = clap::value_parser!(u8).range(1..=9))]
+    lives: Option<u8>,
+
+    /// The interval in ticks between appearances of the mystery ship [default: 2000]
+    #[clap(long)]
+    mystery_ship_interval: Option<u16>,
+
+    /// The maximum number of cannon lasers that can be present [default: 1]
+    #[clap(long)]
+    max_cannon_lasers: Option<u8>,
+
+    /// The maximum number of invader lasers that can be present [default: 3]
+    #[clap(long)]
+    max_invader_lasers: Option<u8>,
+
+    /// The number of milliseconds per tick [default: 50]
+    #[clap(long)]
+    tick_length: Option<u64>,
+
+    /// The number of ticks between grid moves with a full grid [default: 5]
+    #[clap(long)]
+    march_delay: Option<u8>,
+
+    /// How quickly the grid speeds up as invaders are destroyed, where 1 speeds up in proportion
+    /// to the invaders remaining and larger values speed up sooner [default: 1.0]
+    #[clap(long)]
+    march_curve: Option<f64>,
+
+    /// The level to start at [default: 0]
+    #[clap(long)]
+    start_level: Option<u8>,
+
+    /// The number of times the grid has moved down at the start of each wave, separated by commas
+    /// and repeating once every entry is used [default: 0,3,5,6,6,6,7,7,7]
+    #[clap(long, value_name = "ROWS", value_delimiter = ',')]
+    start_rows: Option<Vec<u8>>,
+
+    /// The percentage chance that a rolling bomb survives hitting a cannon laser [default: 0]
+    #[clap(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+    rolling_survival: Option<u8>,
+
+    /// The percentage chance that a plunger bomb survives hitting a cannon laser [default: 0]
+    #[clap(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+    plunger_survival: Option<u8>,
+
+    /// The percentage chance that a squiggly bomb survives hitting a cannon laser [default: 0]
+    #[clap(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+    squiggly_survival: Option<u8>,
+
+    /// The score at which a bonus life is awarded [default: 1500]
+    #[clap(long)]
+    bonus_life_score: Option<u32>,
+
+    /// Award another bonus life every N points after the first
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..))]
+    bonus_life_repeat: Option<u32>,
+
+    /// The number of bunkers, spread evenly across the playfield [default: 4]
+    #[clap(long, value_parser = clap::value_parser!(u8).range(0..=settings::MAX_BUNKERS as i64))]
+    bunkers: Option<u8>,
+
+    /// The left edges of the bunkers, separated by commas, overriding --bunkers
+    #[clap(long, value_name = "X", value_delimiter = ',')]
+    bunker_positions: Option<Vec<f64>>,
+
+    /// Restore the bunkers every N waves, rather than only at the start of each game
+    #[clap(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bunker_restore: Option<u32>,
+
+    /// The theme to draw the game in, either built in or defined in the config file
+    #[clap(long, value_name = "NAME")]
+    theme: Option<String>,
+
+    /// Colour the playfield by screen band, like the coloured film on the arcade screen
+    #[clap(long)]
+    cellophane: bool,
+}
+
+/// The backends sound effects can be played with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum SoundBackend {
+    /// Use the audio player if it can be started, otherwise the terminal bell
+    Auto,
+    /// Stream every sound to an audio player
+    Player,
+    /// Ring the terminal bell for the most important sounds
+    Bell,
+    /// Play no sounds
+    None,
+    /// Render sounds to a WAV file
+    #[cfg(feature = "wav")]
+    Wav,
+}
+
+impl Args {
+    ///
+    fn sound_sink(&self) -> io::Result<Box<dyn SoundSink>> {
+        let player = match &self.sound_player {
+            Some(command) => command.split_whitespace().map(str::to_string).collect(),
+            None => sound::default_player(),
+        };
+
+        Ok(match self.sound {
+            SoundBackend::Auto => match PlayerSink::spawn(&player, BellSink::new(io::stdout())) {
+                Ok(sink) => Box::new(sink),
+                Err(_) => Box::new(BellSink::new(io::stdout())),
+            },
+            SoundBackend::Player => {
+                Box::new(PlayerSink::spawn(&player, BellSink::new(io::stdout()))?)
+            }
+            SoundBackend::Bell => Box::new(BellSink::new(io::stdout())),
+            SoundBackend::None => Box::new(NullSink),
+            #[cfg(feature = "wav")]
+            SoundBackend::Wav => Box::new(sound::WavSink::create(&self.sound_file)?),
+        })
+    }
+
+    /// Override settings loaded from the config file with any given on the command line.
+    fn apply(&self, settings: &mut Settings) {
+        if let Some(lives) = self.lives {
+            settings.lives = lives;
+        }
+
+        if let Some(interval) = self.mystery_ship_interval {
+            settings.mystery_ship_interval = interval;
+        }
+
+        if let Some(max) = self.max_cannon_lasers {
+            settings.max_cannon_lasers = max;
+        }
+
+        if let Some(max) = self.max_invader_lasers {
+            settings.max_invader_lasers = max;
+        }
+
+        if let Some(tick_length) = self.tick_length {
+            settings.tick_length = tick_length;
+        }
+
+        if let Some(delay) = self.march_delay {
+            settings.march_delay = delay;
+        }
+
+        if let Some(curve) = self.march_curve {
+            settings.march_curve = curve;
+        }
+
+        if let Some(level) = self.start_level {
+            settings.start_level = level;
+        }
+
+        if let Some(rows) = &self.start_rows {
+            settings.start_rows = rows.clone();
+        }
+
+        if let Some(survival) = self.rolling_survival {
+            settings.rolling_survival = survival;
+        }
+
+        if let Some(survival) = self.plunger_survival {
+            settings.plunger_survival = survival;
+        }
+
+        if let Some(survival) = self.squiggly_survival {
+            settings.squiggly_survival = survival;
+        }
+
+        if let Some(score) = self.bonus_life_score {
+            settings.bonus_life_score = score;
+        }
+
+        if self.bonus_life_repeat.is_some() {
+            settings.bonus_life_repeat = self.bonus_life_repeat;
+        }
+
+        if let Some(bunkers) = self.bunkers {
+            settings.bunkers = bunkers;
+            settings.bunker_positions.clear();
+        }
 
-    /// The maximum number of cannon lasers that can be present.
-    #[clap(long, default_value_t = 1)]
-    max_cannon_lasers: u8,
+        if let Some(positions) = &self.bunker_positions {
+            settings.bunker_positions = positions.clone();
+        }
 
-    /// The maximum number of invader lasers that can be present.
-    #[clap(long, default_value_t = 3)]
-    max_invader_lasers: u8,
+        if self.bunker_restore.is_some() {
+            settings.bunker_restore = self.bunker_restore;
+        }
 
-    /// The number of milliseconds per tick.
-    #[clap(long, default_value_t = 50)]
-    tick_length: u64,
+        if self.theme.is_some() {
+            settings.theme = self.theme.clone();
+        }
 
-    /// The level to start at.
-    #[clap(long, default_value_t = 0)]
-    start_level: u8,
+        if self.cellophane {
+            settings.cellophane = true;
+        }
+
+        if let Some(renderer) = self.renderer {
+            settings.renderer = renderer;
+        }
+    }
 }
 
 fn main() -> Result<(), io::Error> {
     let args = Args::parse();
+    let config_path = args.config.clone().unwrap_or_else(paths::config_file);
+    let mut settings = Settings::load(&config_path)?;
+    args.apply(&mut settings);
+    settings.validate()?;
+    let sink = args.sound_sink()?;
+    let event_log = match &args.event_log {
+        Some(path) => Some(EventLog::new(io::BufWriter::new(File::create(path)?))),
+        None => None,
+    };
+
+    // broken statistics or achievements files shouldn't stop the game, so are reported on the
+    // statistics and achievements screens instead
+    let data_dir = paths::data_dir();
+    let mut load_errors = Vec::new();
+    let lifetime = LifetimeStats::load(&data_dir.join(app::STATS_FILE)).unwrap_or_else(|err| {
+        load_errors.push(format!("Failed to load statistics: {err}"));
+        LifetimeStats::default()
+    });
+    let achievements =
+        Achievements::load(&data_dir.join(app::ACHIEVEMENTS_FILE)).unwrap_or_else(|err| {
+            load_errors.push(format!("Failed to load achievements: {err}"));
+            Achievements::default()
+        });
 
     enable_raw_mode()?;
+    // restores the terminal however the game ends, including by returning an error
+    let guard = TerminalGuard;
 
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableFocusChange
+    )?;
 
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
@@ -57,61 +323,122 @@ fn main() -> Result<(), io::Error> {
     panic_hook();
 
     let mut app = app::App::new(
-        args.mystery_ship_interval,
-        args.max_cannon_lasers,
-        args.max_invader_lasers,
-        args.start_level,
-    );
-    let tick_rate = Duration::from_millis(args.tick_length);
+        settings,
+        config_path,
+        lifetime,
+        achievements,
+        Some(data_dir),
+    )?;
+    if !load_errors.is_empty() {
+        app.stats_message = Some(load_errors.join("; "));
+    }
+    app.muted = args.mute;
+    app.zoom = args.zoom;
+    app.no_color = theme::no_color();
+    app.subscribe(SoundEffects::new(sink));
+
+    if let Some(event_log) = event_log {
+        app.subscribe(event_log);
+    }
+
     let mut last_tick = Instant::now();
 
     loop {
+        // the game can't be seen while the terminal is too small, so shouldn't carry on either
+        if ui::is_too_small(terminal.size()?) {
+            app.on_too_small();
+        }
+
         terminal.draw(|f| ui::draw(f, &app))?;
+        let tick_rate = Duration::from_millis(app.tick_length());
+
+        app.take_events()?;
+
+        // checked after handling events so those emitted while quitting are still recorded
+        if app.should_quit {
+            break;
+        }
+
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
 
         if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                match key.modifiers {
-                    KeyModifiers::CONTROL => {
-                        if key.code == KeyCode::Char('c') {
-                            app.on_ctrl_c();
-                        }
-                    }
+            match event::read()? {
+                // any key ends a demo game without doing anything else
+                Event::Key(key) if !app.wake() => match key.modifiers {
+                    KeyModifiers::CONTROL if key.code == KeyCode::Char('c') => app.on_ctrl_c(),
                     KeyModifiers::NONE => match key.code {
+                        KeyCode::Char('a') => app.on_a(),
+                        KeyCode::Char('n') => app.on_n(),
+                        KeyCode::Char('y') => app.on_y(),
                         KeyCode::Char('h') => app.on_h(),
+                        KeyCode::Char('m') => app.on_m(),
                         KeyCode::Char('p') => app.on_p(),
                         KeyCode::Char('q') => app.on_q(),
+                        KeyCode::Char('r') => app.on_r(),
+                        KeyCode::Char('s') => app.on_s(),
+                        KeyCode::Char('t') => app.on_t(),
+                        KeyCode::Char('w') => app.on_w(),
+                        KeyCode::Char('z') => app.on_z(),
                         KeyCode::Char(' ') => app.on_space(),
+                        KeyCode::Enter => app.on_enter(),
+                        KeyCode::Up => app.on_up(),
+                        KeyCode::Down => app.on_down(),
                         KeyCode::Left => app.on_left(),
                         KeyCode::Right => app.on_right(),
                         KeyCode::Esc => app.on_esc(),
                         _ => {}
                     },
                     _ => {}
-                }
+                },
+                Event::FocusLost => app.on_focus_lost(),
+                Event::Mouse(mouse) => on_mouse(&mut app, mouse, terminal.size()?),
+                _ => {}
             }
         }
 
-        if app.should_quit {
-            break;
-        }
-
         if last_tick.elapsed() >= tick_rate {
             if app.playing() {
                 app.on_tick();
+            } else {
+                app.on_idle_tick();
             }
 
+            // sounds play out to the end after the game stops, for example at game over
+            app.listeners_on_tick(tick_rate)?;
+
             last_tick = Instant::now();
         }
     }
 
-    reset_terminal()?;
+    drop(guard);
+    app.finish()?;
 
     Ok(())
 }
 
+/// Handle hovering and clicking on the pause menu and quit confirmation.
+fn on_mouse(app: &mut app::App, mouse: MouseEvent, size: Rect) {
+    match mouse.kind {
+        MouseEventKind::Moved => {
+            if let Some(index) = ui::pause_menu_item_at(app, size, mouse.column, mouse.row) {
+                app.on_pause_menu_hover(index);
+            }
+        }
+        MouseEventKind::Down(MouseButton::Left) if !app.wake() => {
+            if app.confirm_quit {
+                if let Some(yes) = ui::confirm_quit_button_at(app, size, mouse.column, mouse.row) {
+                    app.on_confirm_quit_click(yes);
+                }
+            } else if let Some(index) = ui::pause_menu_item_at(app, size, mouse.column, mouse.row) {
+                app.on_pause_menu_click(index);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn panic_hook() {
     let original_hook = std::panic::take_hook();
 
@@ -121,22 +448,24 @@ fn panic_hook() {
     }));
 }
 
+/// Resets the terminal when dropped.
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // there is nowhere left to report a failure to restore the terminal
+        let _ = reset_terminal();
+    }
+}
+
 fn reset_terminal() -> io::Result<()> {
     disable_raw_mode()?;
-    crossterm::execute!(io::stdout(), LeaveAlternateScreen)?;
+    crossterm::execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableFocusChange
+    )?;
 
     Ok(())
 }
-
-//
-// fn xdg_cache_home() -> PathBuf {
-//     match env::var_os("XDG_CACHE_HOME") {
-//         Some(cache_home) => PathBuf::from(cache_home),
-//         None => {
-//             let mut home_dir = home::home_dir().expect("Unable to get home directory");
-//             home_dir.push(".cache");
-
-//             home_dir
-//         }
-//     }
-// }
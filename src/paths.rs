@@ -0,0 +1,93 @@
+//!
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+const APP_DIR: &str = "invaders";
+const CONFIG_FILE: &str = "config.toml";
+
+/// The directory persistent game data is stored in, within the XDG data directory.
+pub fn data_dir() -> PathBuf {
+    let mut path = xdg_home("XDG_DATA_HOME", ".local/share");
+    path.push(APP_DIR);
+
+    path
+}
+
+/// The default location of the config file, within the XDG config directory.
+pub fn config_file() -> PathBuf {
+    let mut path = xdg_home("XDG_CONFIG_HOME", ".config");
+    path.push(APP_DIR);
+    path.push(CONFIG_FILE);
+
+    path
+}
+
+/// Read a value from a TOML file, falling back to the default if the file doesn't exist.
+pub fn load_toml<T: DeserializeOwned + Default>(path: &Path) -> io::Result<T> {
+    match fs::read_to_string(path) {
+        Ok(contents) => {
+            toml::from_str(&contents).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Write a value to a TOML file, creating its directory if needed.
+pub fn save_toml<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let contents =
+        toml::to_string(value).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, contents)
+}
+
+fn xdg_home(var: &str, fallback: &str) -> PathBuf {
+    match env::var_os(var) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let mut home_dir = home::home_dir().expect("Unable to get home directory");
+            home_dir.push(fallback);
+
+            home_dir
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stats::LifetimeStats;
+
+    #[test]
+    fn test_toml_round_trip() {
+        let dir = env::temp_dir().join(format!("invaders-test-{}", std::process::id()));
+        let path = dir.join("nested").join("stats.toml");
+
+        let missing: LifetimeStats = load_toml(&path).unwrap();
+        assert_eq!(missing.games_played, 0);
+
+        let stats = LifetimeStats {
+            games_played: 3,
+            ..Default::default()
+        };
+        save_toml(&path, &stats).unwrap();
+
+        let loaded: LifetimeStats = load_toml(&path).unwrap();
+        assert_eq!(loaded.games_played, 3);
+
+        fs::write(&path, "games_played = \"three\"").unwrap();
+        assert!(load_toml::<LifetimeStats>(&path).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+//!
+
+pub const BOMB_FRAMES: usize = 4;
+
+/// ⢸⠁ ⢺ ⢸⠄ ⣸
+pub static ROLLING: [[(f64, f64); 5]; BOMB_FRAMES] = [
+    [(1.0, 0.0), (1.0, 1.0), (1.0, 2.0), (1.0, 3.0), (2.0, 3.0)],
+    [(1.0, 0.0), (1.0, 1.0), (0.0, 2.0), (1.0, 2.0), (1.0, 3.0)],
+    [(1.0, 0.0), (1.0, 1.0), (2.0, 1.0), (1.0, 2.0), (1.0, 3.0)],
+    [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (1.0, 2.0), (1.0, 3.0)],
+];
+
+/// ⣸⡀ ⢼⠄ ⢺⠂ ⢹⠁
+pub static PLUNGER: [[(f64, f64); 6]; BOMB_FRAMES] = [
+    [
+        (0.0, 0.0),
+        (1.0, 0.0),
+        (2.0, 0.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (1.0, 3.0),
+    ],
+    [
+        (1.0, 0.0),
+        (0.0, 1.0),
+        (1.0, 1.0),
+        (2.0, 1.0),
+        (1.0, 2.0),
+        (1.0, 3.0),
+    ],
+    [
+        (1.0, 0.0),
+        (1.0, 1.0),
+        (0.0, 2.0),
+        (1.0, 2.0),
+        (2.0, 2.0),
+        (1.0, 3.0),
+    ],
+    [
+        (1.0, 0.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (0.0, 3.0),
+        (1.0, 3.0),
+        (2.0, 3.0),
+    ],
+];
+
+/// ⡨⠂ ⢑⠄ ⠪⡀ ⢔⠁
+pub static SQUIGGLY: [[(f64, f64); 4]; BOMB_FRAMES] = [
+    [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (1.0, 3.0)],
+    [(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (0.0, 3.0)],
+    [(2.0, 0.0), (1.0, 1.0), (0.0, 2.0), (1.0, 3.0)],
+    [(1.0, 0.0), (0.0, 1.0), (1.0, 2.0), (2.0, 3.0)],
+];
@@ -0,0 +1,145 @@
+//!
+
+/// ⡱⡱⡁
+pub static EXPLOSION: [(f64, f64); 10] = [
+    (0.0, 0.0),
+    (2.0, 0.0),
+    (4.0, 0.0),
+    (1.0, 1.0),
+    (3.0, 1.0),
+    (1.0, 2.0),
+    (3.0, 2.0),
+    (0.0, 3.0),
+    (2.0, 3.0),
+    (4.0, 3.0),
+];
+
+/// ⣡⣮⣴⡌
+/// ⡘⠟⡻⢋
+pub static CANNON_LASER_MASK: [(f64, f64); 36] = [
+    (0.0, 0.0),
+    (4.0, 0.0),
+    (7.0, 0.0),
+    (2.0, 1.0),
+    (5.0, 1.0),
+    (1.0, 2.0),
+    (2.0, 2.0),
+    (3.0, 2.0),
+    (4.0, 2.0),
+    (5.0, 2.0),
+    (6.0, 2.0),
+    (1.0, 3.0),
+    (2.0, 3.0),
+    (3.0, 3.0),
+    (4.0, 3.0),
+    (5.0, 3.0),
+    (6.0, 3.0),
+    (7.0, 3.0),
+    (0.0, 4.0),
+    (1.0, 4.0),
+    (2.0, 4.0),
+    (3.0, 4.0),
+    (4.0, 4.0),
+    (5.0, 4.0),
+    (6.0, 4.0),
+    (1.0, 5.0),
+    (2.0, 5.0),
+    (3.0, 5.0),
+    (4.0, 5.0),
+    (5.0, 5.0),
+    (6.0, 5.0),
+    (2.0, 6.0),
+    (5.0, 6.0),
+    (0.0, 7.0),
+    (3.0, 7.0),
+    (7.0, 7.0),
+];
+
+/// ⢂⣥⣆
+/// ⡑⢟⠏
+pub static ROLLING_MASK: [(f64, f64); 24] = [
+    (0.0, 0.0),
+    (3.0, 0.0),
+    (2.0, 1.0),
+    (4.0, 1.0),
+    (1.0, 2.0),
+    (2.0, 2.0),
+    (3.0, 2.0),
+    (4.0, 2.0),
+    (0.0, 3.0),
+    (2.0, 3.0),
+    (3.0, 3.0),
+    (4.0, 3.0),
+    (5.0, 3.0),
+    (1.0, 4.0),
+    (2.0, 4.0),
+    (3.0, 4.0),
+    (4.0, 4.0),
+    (5.0, 4.0),
+    (2.0, 5.0),
+    (3.0, 5.0),
+    (4.0, 5.0),
+    (0.0, 6.0),
+    (4.0, 6.0),
+    (2.0, 7.0),
+];
+
+/// ⣨⣴⣅
+/// ⢊⠟⡑
+pub static PLUNGER_MASK: [(f64, f64); 24] = [
+    (1.0, 0.0),
+    (4.0, 0.0),
+    (2.0, 1.0),
+    (0.0, 2.0),
+    (2.0, 2.0),
+    (3.0, 2.0),
+    (5.0, 2.0),
+    (1.0, 3.0),
+    (2.0, 3.0),
+    (3.0, 3.0),
+    (4.0, 3.0),
+    (0.0, 4.0),
+    (1.0, 4.0),
+    (2.0, 4.0),
+    (3.0, 4.0),
+    (4.0, 4.0),
+    (5.0, 4.0),
+    (1.0, 5.0),
+    (2.0, 5.0),
+    (3.0, 5.0),
+    (4.0, 5.0),
+    (3.0, 6.0),
+    (1.0, 7.0),
+    (4.0, 7.0),
+];
+
+/// ⣡⣦⡕
+/// ⠜⡻⢋
+pub static SQUIGGLY_MASK: [(f64, f64); 26] = [
+    (2.0, 0.0),
+    (5.0, 0.0),
+    (0.0, 1.0),
+    (3.0, 1.0),
+    (1.0, 2.0),
+    (2.0, 2.0),
+    (3.0, 2.0),
+    (4.0, 2.0),
+    (1.0, 3.0),
+    (2.0, 3.0),
+    (3.0, 3.0),
+    (4.0, 3.0),
+    (5.0, 3.0),
+    (0.0, 4.0),
+    (1.0, 4.0),
+    (2.0, 4.0),
+    (3.0, 4.0),
+    (4.0, 4.0),
+    (1.0, 5.0),
+    (2.0, 5.0),
+    (3.0, 5.0),
+    (4.0, 5.0),
+    (2.0, 6.0),
+    (5.0, 6.0),
+    (0.0, 7.0),
+    (4.0, 7.0),
+];
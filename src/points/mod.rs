@@ -1,13 +1,17 @@
 //!
 
+mod bombs;
 mod bunker;
 mod cannon;
+mod explosion;
 mod invaders;
 pub mod letters;
 mod mystery_ship;
 
+pub use bombs::{BOMB_FRAMES, PLUNGER, ROLLING, SQUIGGLY};
 pub use bunker::BUNKER;
 pub use cannon::CANNON;
+pub use explosion::{CANNON_LASER_MASK, EXPLOSION, PLUNGER_MASK, ROLLING_MASK, SQUIGGLY_MASK};
 pub use invaders::{CRAB, CRAB_ALT, OCTOPUS, OCTOPUS_ALT, SQUID, SQUID_ALT};
 pub use mystery_ship::MYSTERY_SHIP;
 
@@ -35,13 +39,31 @@ pub const ALIEN_BUFFER_WIDTH: f64 = 4.0;
 pub const LASER_WIDTH: f64 = 1.0;
 pub const LASER_HEIGHT: f64 = 2.0;
 
+pub const BOMB_WIDTH: f64 = 3.0;
+pub const BOMB_HEIGHT: f64 = 4.0;
+
+pub const EXPLOSION_WIDTH: f64 = 5.0;
+pub const EXPLOSION_TICKS: u8 = 4;
+
+pub const CANNON_LASER_MASK_WIDTH: f64 = 8.0;
+pub const BOMB_MASK_WIDTH: f64 = 6.0;
+pub const MASK_HEIGHT: f64 = 8.0;
+
 pub const MYSTERY_SHIP_WIDTH: f64 = 16.0;
 pub const MYSTERY_SHIP_HEIGHT: f64 = 8.0;
 
 pub const MYSTERY_SHIP_GRID_BUFFER: f64 = 2.0;
 
+pub const GROUND_Y: f64 = 0.0;
+
 pub const CANNON_INITIAL_X: f64 = 1.0;
-pub const CANNON_INITIAL_Y: f64 = 0.0;
+pub const CANNON_INITIAL_Y: f64 = GROUND_Y + 2.0;
+
+// invaders reaching the top of the cannon's row have invaded, ending the game
+pub const INVASION_Y: f64 = CANNON_INITIAL_Y + CANNON_HEIGHT;
+
+pub const LIFE_INITIAL_Y: f64 = 0.0;
+pub const LIFE_SPACING_X: f64 = 4.0;
 
 pub const BUNKER_OFFSET_X: f64 = 20.0;
 pub const BUNKER_INITIAL_Y: f64 = 14.0;
@@ -49,9 +71,13 @@ pub const BUNKER_INITIAL_Y: f64 = 14.0;
 pub const MYSTERY_SHIP_INITIAL_X: f64 = GAME_WIDTH + 1.0;
 pub const MYSTERY_SHIP_INITIAL_Y: f64 = GAME_HEIGHT - MYSTERY_SHIP_HEIGHT;
 
-pub const CANNON_LASER_INITIAL_Y: f64 = CANNON_HEIGHT + 1.0;
+pub const CANNON_LASER_INITIAL_Y: f64 = CANNON_INITIAL_Y + CANNON_HEIGHT + 1.0;
 pub const CANNON_LASER_INITIAL_X_OFFSET: f64 = (CANNON_WIDTH / 2.0) - 1.0;
 
+// the strip of coloured film across the top of the arcade screen covers the mystery ship, while the
+// one across the bottom reaches up to the top of the bunkers, which depends on their shape
+pub const CELLOPHANE_TOP_Y: f64 = MYSTERY_SHIP_INITIAL_Y;
+
 pub const GRID_INITIAL_X: f64 = 1.0;
 pub const GRID_INITIAL_Y: f64 =
     MYSTERY_SHIP_INITIAL_Y - (5.0 * ROW_HEIGHT) - MYSTERY_SHIP_GRID_BUFFER;
@@ -61,6 +87,9 @@ pub const INVADER_MOVE_Y: f64 = ROW_HEIGHT / 2.0;
 pub const MYSTERY_SHIP_MOVE: f64 = 2.0;
 pub const CANNON_MOVE: f64 = 2.0;
 pub const LASER_MOVE: f64 = 2.0;
+pub const ROLLING_MOVE: f64 = 2.0;
+pub const PLUNGER_MOVE: f64 = 1.0;
+pub const SQUIGGLY_MOVE: f64 = 3.0;
 
 /// ⡄
 pub static LASER: [(f64, f64); 2] = [(0.0, 0.0), (0.0, 1.0)];
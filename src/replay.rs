@@ -0,0 +1,79 @@
+//!
+
+use crate::settings::Settings;
+
+/// A move made by the player during a game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Input {
+    Left,
+    Right,
+    Fire,
+}
+
+/// A recording of a game, from which it can be played again exactly as it happened. Everything else
+/// in a game follows from its rules, the seed of its random numbers and the moves made on each tick.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replay {
+    pub seed: u64,
+    pub rules: Settings,
+    inputs: Vec<(u64, Input)>,
+    position: usize,
+}
+
+impl Replay {
+    /// Start recording a game.
+    pub fn new(seed: u64, rules: Settings) -> Self {
+        Self {
+            seed,
+            rules,
+            inputs: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Record a move made before the given tick was played.
+    pub fn record(&mut self, tick: u64, input: Input) {
+        self.inputs.push((tick, input));
+    }
+
+    /// Start playing back from the beginning.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    /// Take the next move to play back before the given tick, if there is one.
+    pub fn next_input(&mut self, tick: u64) -> Option<Input> {
+        let (input_tick, input) = *self.inputs.get(self.position)?;
+
+        if input_tick > tick {
+            return None;
+        }
+
+        self.position += 1;
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_play_back() {
+        let mut replay = Replay::new(1, Settings::default());
+        replay.record(0, Input::Left);
+        replay.record(0, Input::Fire);
+        replay.record(2, Input::Right);
+
+        for _ in 0..2 {
+            replay.rewind();
+
+            assert_eq!(replay.next_input(0), Some(Input::Left));
+            assert_eq!(replay.next_input(0), Some(Input::Fire));
+            assert_eq!(replay.next_input(0), None);
+            assert_eq!(replay.next_input(1), None);
+            assert_eq!(replay.next_input(2), Some(Input::Right));
+            assert_eq!(replay.next_input(3), None);
+        }
+    }
+}
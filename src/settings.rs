@@ -0,0 +1,583 @@
+//!
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assets::BunkerShape;
+use crate::levels::{self, MAX_LEVEL, MAX_START_ROWS};
+use crate::paths;
+use crate::points;
+use crate::theme::{self, Theme};
+use crate::ui::Renderer;
+
+/// The most bunkers that can be spread evenly across the playfield.
+pub const MAX_BUNKERS: u8 = 8;
+
+// the ranges settings must be within, which the settings screen steps between
+const LIVES: RangeInclusive<u8> = 1..=9;
+const BONUS_LIFE_SCORE: RangeInclusive<u32> = 500..=10000;
+const MAX_LASERS: RangeInclusive<u8> = 1..=9;
+const MYSTERY_SHIP_INTERVAL: RangeInclusive<u16> = 250..=10000;
+const TICK_LENGTH: RangeInclusive<u64> = 10..=200;
+const BUNKER_RESTORE: RangeInclusive<u32> = 1..=9;
+const SURVIVAL: RangeInclusive<u8> = 0..=100;
+
+/// The settings that tune gameplay, modelled on the DIP switches of the arcade original. These can
+/// be loaded from a config file, overridden by command line flags and changed from the settings
+/// screen.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub lives: u8,
+    pub bonus_life_score: u32,
+    pub bonus_life_repeat: Option<u32>,
+    pub max_cannon_lasers: u8,
+    pub max_invader_lasers: u8,
+    pub mystery_ship_interval: u16,
+    pub start_level: u8,
+    /// The number of times the grid has moved down at the start of each wave, in order.
+    pub start_rows: Vec<u8>,
+    pub tick_length: u64,
+    pub march_delay: u8,
+    pub march_curve: f64,
+    pub rolling_survival: u8,
+    pub plunger_survival: u8,
+    pub squiggly_survival: u8,
+    /// The number of bunkers, spread evenly across the playfield.
+    pub bunkers: u8,
+    /// The left edges of the bunkers, which override `bunkers` if given.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bunker_positions: Vec<f64>,
+    /// The shape of the bunkers as rows of text from the top down, where `#` is solid.
+    pub bunker_shape: Option<Vec<String>>,
+    /// Restore the bunkers every N waves, rather than only at the start of each game.
+    pub bunker_restore: Option<u32>,
+    /// The name of the theme to draw the game in, either built in or from `themes`.
+    pub theme: Option<String>,
+    /// Themes defined in the config file, by name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub themes: BTreeMap<String, Theme>,
+    /// Colour the playfield by the strips of coloured film on the arcade screen, rather than by
+    /// what is drawn.
+    pub cellophane: bool,
+    /// How to draw the game, for terminals whose fonts can't show braille.
+    pub renderer: Renderer,
+}
+
+impl Settings {
+    /// Load settings from a config file, falling back to the defaults if it doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        paths::load_toml(path)
+    }
+
+    /// Write the settings to a config file, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        paths::save_toml(path, self)
+    }
+
+    /// Check the settings make sense together, which can't be done while loading them because
+    /// command line flags may override them.
+    pub fn validate(&self) -> io::Result<()> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+
+        check_range("lives", self.lives, LIVES)?;
+        check_range("bonus_life_score", self.bonus_life_score, BONUS_LIFE_SCORE)?;
+        check_range("max_cannon_lasers", self.max_cannon_lasers, MAX_LASERS)?;
+        check_range("max_invader_lasers", self.max_invader_lasers, MAX_LASERS)?;
+        check_range(
+            "mystery_ship_interval",
+            self.mystery_ship_interval,
+            MYSTERY_SHIP_INTERVAL,
+        )?;
+        check_range("start_level", self.start_level, 0..=MAX_LEVEL)?;
+        check_range("tick_length", self.tick_length, TICK_LENGTH)?;
+        check_range("rolling_survival", self.rolling_survival, SURVIVAL)?;
+        check_range("plunger_survival", self.plunger_survival, SURVIVAL)?;
+        check_range("squiggly_survival", self.squiggly_survival, SURVIVAL)?;
+        check_range("bunkers", self.bunkers, 0..=MAX_BUNKERS)?;
+
+        if !(self.march_curve.is_finite() && self.march_curve >= 0.0) {
+            return Err(invalid(format!(
+                "march_curve must be zero or more, not {}",
+                self.march_curve
+            )));
+        }
+
+        if let Some(repeat) = self.bonus_life_repeat {
+            check_range("bonus_life_repeat", repeat, BONUS_LIFE_SCORE)?;
+        }
+
+        if let Some(waves) = self.bunker_restore {
+            check_range("bunker_restore", waves, BUNKER_RESTORE)?;
+        }
+
+        if let Some(name) = &self.theme {
+            if Theme::find(name, &self.themes).is_none() {
+                return Err(invalid(format!("unknown theme \"{name}\"")));
+            }
+        }
+
+        if self.bunker_positions.len() > MAX_BUNKERS as usize {
+            return Err(invalid(format!(
+                "bunker_positions can have at most {MAX_BUNKERS} bunkers, not {}",
+                self.bunker_positions.len()
+            )));
+        }
+
+        let shape = self.bunker_shape().map_err(invalid)?;
+        let max_left = points::GAME_WIDTH - shape.width();
+
+        if let Some(left) = self
+            .bunker_positions
+            .iter()
+            .find(|left| !(0.0..=max_left).contains(*left))
+        {
+            return Err(invalid(format!(
+                "bunker position {left} is off the playfield, which fits bunkers from 0 to {max_left}"
+            )));
+        }
+
+        if let Some(rows) = self.start_rows.iter().find(|rows| **rows > MAX_START_ROWS) {
+            return Err(invalid(format!(
+                "start rows {rows} would start the invaders on the cannon's row, which allows up to {MAX_START_ROWS}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// The shape of the bunkers, which is the arcade original unless one is given.
+    pub fn bunker_shape(&self) -> Result<BunkerShape, String> {
+        match &self.bunker_shape {
+            Some(rows) => BunkerShape::parse(rows),
+            None => Ok(BunkerShape::classic()),
+        }
+    }
+
+    /// The number of bunkers there will be, whether spread evenly or given positions.
+    pub fn bunker_count(&self) -> u8 {
+        if self.bunker_positions.is_empty() {
+            self.bunkers
+        } else {
+            self.bunker_positions.len().min(u8::MAX as usize) as u8
+        }
+    }
+
+    /// The theme to draw the game in. A chosen theme always wins, otherwise the game is drawn
+    /// without colours if `NO_COLOR` is set.
+    pub fn theme(&self, no_color: bool) -> Theme {
+        match self
+            .theme
+            .as_ref()
+            .and_then(|name| Theme::find(name, &self.themes))
+        {
+            Some(theme) => theme,
+            None if no_color => Theme::monochrome(),
+            None => Theme::classic(),
+        }
+    }
+
+    /// The names of all the themes that can be chosen, built in ones first.
+    pub fn theme_names(&self) -> Vec<&str> {
+        let custom = self
+            .themes
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !theme::BUILT_IN.contains(name));
+
+        theme::BUILT_IN.into_iter().chain(custom).collect()
+    }
+
+    /// The survival odds for each kind of bomb, in the same order as `LaserKind::BOMBS`.
+    pub fn bomb_survival(&self) -> [u8; 3] {
+        [
+            self.rolling_survival,
+            self.plunger_survival,
+            self.squiggly_survival,
+        ]
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            lives: 3,
+            bonus_life_score: 1500,
+            bonus_life_repeat: None,
+            max_cannon_lasers: 1,
+            max_invader_lasers: 3,
+            mystery_ship_interval: 2000,
+            start_level: 0,
+            start_rows: levels::CLASSIC_START_ROWS.to_vec(),
+            tick_length: 50,
+            march_delay: 5,
+            march_curve: 1.0,
+            rolling_survival: 0,
+            plunger_survival: 0,
+            squiggly_survival: 0,
+            bunkers: 4,
+            bunker_positions: Vec::new(),
+            bunker_shape: None,
+            bunker_restore: None,
+            theme: None,
+            themes: BTreeMap::new(),
+            cellophane: false,
+            renderer: Renderer::Braille,
+        }
+    }
+}
+
+/// The settings that can be changed from the settings screen.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SettingsItem {
+    Lives,
+    BonusLifeScore,
+    BonusLifeRepeat,
+    MaxCannonLasers,
+    MaxInvaderLasers,
+    MysteryShipInterval,
+    StartLevel,
+    TickLength,
+    Bunkers,
+    BunkerRestore,
+    Theme,
+    Cellophane,
+    Renderer,
+}
+
+impl SettingsItem {
+    ///
+    pub const ALL: [Self; 13] = [
+        Self::Lives,
+        Self::BonusLifeScore,
+        Self::BonusLifeRepeat,
+        Self::MaxCannonLasers,
+        Self::MaxInvaderLasers,
+        Self::MysteryShipInterval,
+        Self::StartLevel,
+        Self::TickLength,
+        Self::Bunkers,
+        Self::BunkerRestore,
+        Self::Theme,
+        Self::Cellophane,
+        Self::Renderer,
+    ];
+
+    ///
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Lives => "Lives",
+            Self::BonusLifeScore => "Bonus life at",
+            Self::BonusLifeRepeat => "Bonus life every",
+            Self::MaxCannonLasers => "Cannon lasers",
+            Self::MaxInvaderLasers => "Invader lasers",
+            Self::MysteryShipInterval => "Mystery ship interval",
+            Self::StartLevel => "Start level",
+            Self::TickLength => "Tick length",
+            Self::Bunkers => "Bunkers",
+            Self::BunkerRestore => "Restore bunkers",
+            Self::Theme => "Theme",
+            Self::Cellophane => "Cellophane overlay",
+            Self::Renderer => "Renderer",
+        }
+    }
+
+    ///
+    pub fn value(&self, settings: &Settings) -> String {
+        match self {
+            Self::Lives => settings.lives.to_string(),
+            Self::BonusLifeScore => settings.bonus_life_score.to_string(),
+            Self::BonusLifeRepeat => match settings.bonus_life_repeat {
+                Some(repeat) => repeat.to_string(),
+                None => "Off".to_string(),
+            },
+            Self::MaxCannonLasers => settings.max_cannon_lasers.to_string(),
+            Self::MaxInvaderLasers => settings.max_invader_lasers.to_string(),
+            Self::MysteryShipInterval => format!("{} ticks", settings.mystery_ship_interval),
+            Self::StartLevel => settings.start_level.to_string(),
+            Self::TickLength => format!("{}ms", settings.tick_length),
+            Self::Bunkers => settings.bunker_count().to_string(),
+            Self::BunkerRestore => match settings.bunker_restore {
+                Some(1) => "Every wave".to_string(),
+                Some(waves) => format!("Every {waves} waves"),
+                None => "Each game".to_string(),
+            },
+            Self::Theme => match &settings.theme {
+                Some(name) => name.clone(),
+                None => "Default".to_string(),
+            },
+            Self::Cellophane => if settings.cellophane { "On" } else { "Off" }.to_string(),
+            Self::Renderer => settings.renderer.label().to_string(),
+        }
+    }
+
+    /// Step the setting up or down, staying within its range.
+    pub fn adjust(&self, settings: &mut Settings, increase: bool) {
+        match self {
+            Self::Lives => settings.lives = step_in(settings.lives, 1, LIVES, increase),
+            Self::BonusLifeScore => {
+                settings.bonus_life_score =
+                    step_in(settings.bonus_life_score, 500, BONUS_LIFE_SCORE, increase)
+            }
+            Self::BonusLifeRepeat => {
+                settings.bonus_life_repeat = match (settings.bonus_life_repeat, increase) {
+                    (None, true) => Some(500),
+                    (None, false) => None,
+                    (Some(500), false) => None,
+                    (Some(repeat), _) => Some(step_in(repeat, 500, BONUS_LIFE_SCORE, increase)),
+                }
+            }
+            Self::MaxCannonLasers => {
+                settings.max_cannon_lasers =
+                    step_in(settings.max_cannon_lasers, 1, MAX_LASERS, increase)
+            }
+            Self::MaxInvaderLasers => {
+                settings.max_invader_lasers =
+                    step_in(settings.max_invader_lasers, 1, MAX_LASERS, increase)
+            }
+            Self::MysteryShipInterval => {
+                settings.mystery_ship_interval = step_in(
+                    settings.mystery_ship_interval,
+                    250,
+                    MYSTERY_SHIP_INTERVAL,
+                    increase,
+                )
+            }
+            Self::StartLevel => {
+                settings.start_level = step(settings.start_level, 1, 0, MAX_LEVEL, increase)
+            }
+            Self::TickLength => {
+                settings.tick_length = step_in(settings.tick_length, 5, TICK_LENGTH, increase)
+            }
+            Self::Bunkers => {
+                // changing the number spreads them evenly again
+                settings.bunkers = step(settings.bunker_count(), 1, 0, MAX_BUNKERS, increase);
+                settings.bunker_positions.clear();
+            }
+            Self::BunkerRestore => {
+                settings.bunker_restore = match (settings.bunker_restore, increase) {
+                    (None, true) => Some(1),
+                    (None, false) => None,
+                    (Some(1), false) => None,
+                    (Some(waves), _) => Some(step_in(waves, 1, BUNKER_RESTORE, increase)),
+                }
+            }
+            Self::Theme => {
+                // the default comes before all the named themes
+                let names = settings.theme_names();
+                let index = match &settings.theme {
+                    Some(name) => names.iter().position(|n| n == name).map_or(0, |i| i + 1),
+                    None => 0,
+                };
+
+                settings.theme = match step(index, 1, 0, names.len(), increase) {
+                    0 => None,
+                    index => Some(names[index - 1].to_string()),
+                };
+            }
+            Self::Cellophane => settings.cellophane = increase,
+            Self::Renderer => {
+                let index = Renderer::ALL
+                    .iter()
+                    .position(|renderer| *renderer == settings.renderer)
+                    .unwrap_or(0);
+
+                settings.renderer =
+                    Renderer::ALL[step(index, 1, 0, Renderer::ALL.len() - 1, increase)];
+            }
+        }
+    }
+}
+
+/// Check a setting is within its range, naming it as it appears in the config file if not.
+fn check_range<T: PartialOrd + Display>(
+    name: &str,
+    value: T,
+    range: RangeInclusive<T>,
+) -> io::Result<()> {
+    if range.contains(&value) {
+        return Ok(());
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!(
+            "{name} must be from {} to {}, not {value}",
+            range.start(),
+            range.end()
+        ),
+    ))
+}
+
+fn step_in<T>(value: T, by: T, range: RangeInclusive<T>, increase: bool) -> T
+where
+    T: Copy + Ord + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    step(value, by, *range.start(), *range.end(), increase)
+}
+
+fn step<T>(value: T, by: T, min: T, max: T, increase: bool) -> T
+where
+    T: Copy + Ord + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    if increase {
+        if value >= max || max - value < by {
+            max
+        } else {
+            value + by
+        }
+    } else if value <= min || value - min < by {
+        min
+    } else {
+        value - by
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_adjust_stays_in_range() {
+        let mut settings = Settings::default();
+
+        for _ in 0..20 {
+            SettingsItem::Lives.adjust(&mut settings, true);
+        }
+        assert_eq!(settings.lives, 9);
+
+        for _ in 0..20 {
+            SettingsItem::Lives.adjust(&mut settings, false);
+        }
+        assert_eq!(settings.lives, 1);
+    }
+
+    #[test]
+    fn test_adjust_bonus_life_repeat() {
+        let mut settings = Settings::default();
+
+        SettingsItem::BonusLifeRepeat.adjust(&mut settings, true);
+        assert_eq!(settings.bonus_life_repeat, Some(500));
+
+        SettingsItem::BonusLifeRepeat.adjust(&mut settings, true);
+        assert_eq!(settings.bonus_life_repeat, Some(1000));
+
+        SettingsItem::BonusLifeRepeat.adjust(&mut settings, false);
+        SettingsItem::BonusLifeRepeat.adjust(&mut settings, false);
+        assert_eq!(settings.bonus_life_repeat, None);
+    }
+
+    #[test]
+    fn test_adjust_theme() {
+        let mut settings: Settings = toml::from_str("[themes.mine]\ncannon = \"#ff8000\"").unwrap();
+
+        SettingsItem::Theme.adjust(&mut settings, false);
+        assert_eq!(settings.theme, None);
+
+        SettingsItem::Theme.adjust(&mut settings, true);
+        assert_eq!(settings.theme.as_deref(), Some("classic"));
+
+        for _ in 0..10 {
+            SettingsItem::Theme.adjust(&mut settings, true);
+        }
+        assert_eq!(settings.theme.as_deref(), Some("mine"));
+        assert_eq!(
+            settings.theme(false).cannon,
+            tui::style::Color::Rgb(0xff, 0x80, 0x00)
+        );
+    }
+
+    #[test]
+    fn test_adjust_renderer() {
+        let mut settings: Settings = toml::from_str("renderer = \"half-block\"").unwrap();
+        assert_eq!(settings.renderer, Renderer::HalfBlock);
+
+        SettingsItem::Renderer.adjust(&mut settings, true);
+        SettingsItem::Renderer.adjust(&mut settings, true);
+        assert_eq!(settings.renderer, Renderer::Ascii);
+
+        for _ in 0..5 {
+            SettingsItem::Renderer.adjust(&mut settings, false);
+        }
+        assert_eq!(settings.renderer, Renderer::Braille);
+
+        assert!(toml::from_str::<Settings>("renderer = \"sixel\"").is_err());
+    }
+
+    #[test]
+    fn test_theme_respects_no_color() {
+        let mut settings = Settings::default();
+        assert_eq!(settings.theme(false), Theme::classic());
+        assert_eq!(settings.theme(true), Theme::monochrome());
+
+        settings.theme = Some("high-contrast".to_string());
+        assert_eq!(settings.theme(true), Theme::high_contrast());
+    }
+
+    #[test]
+    fn test_settings_round_trip() {
+        let settings = Settings {
+            bonus_life_repeat: Some(2000),
+            start_level: 4,
+            start_rows: vec![2, 4],
+            theme: Some("mine".to_string()),
+            themes: BTreeMap::from([("mine".to_string(), Theme::colorblind())]),
+            ..Settings::default()
+        };
+
+        let contents = toml::to_string(&settings).unwrap();
+        assert_eq!(toml::from_str::<Settings>(&contents).unwrap(), settings);
+        assert_eq!(toml::from_str::<Settings>("").unwrap(), Settings::default());
+    }
+
+    #[test]
+    fn test_validate_ranges() {
+        let settings = toml::from_str::<Settings>("lives = 0").unwrap();
+        assert!(settings.validate().is_err());
+
+        let settings = toml::from_str::<Settings>("tick_length = 0").unwrap();
+        assert!(settings.validate().is_err());
+
+        let settings = toml::from_str::<Settings>("max_cannon_lasers = 0").unwrap();
+        assert!(settings.validate().is_err());
+
+        let settings = toml::from_str::<Settings>("march_curve = -1.0").unwrap();
+        assert!(settings.validate().is_err());
+
+        let settings = toml::from_str::<Settings>("bunkers = 9").unwrap();
+        assert!(settings.validate().is_err());
+
+        let settings = toml::from_str::<Settings>(
+            "bunker_positions = [0, 20, 40, 60, 80, 100, 120, 140, 160]",
+        )
+        .unwrap();
+        assert!(settings.validate().is_err());
+
+        // everything the settings screen can reach is valid
+        let mut settings = Settings::default();
+        for item in SettingsItem::ALL {
+            for increase in [true, false] {
+                for _ in 0..100 {
+                    item.adjust(&mut settings, increase);
+                    assert!(settings.validate().is_ok(), "{item:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_start_rows() {
+        assert!(Settings::default().validate().is_ok());
+
+        let settings = Settings {
+            start_rows: vec![0, MAX_START_ROWS + 1],
+            ..Settings::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//!
+
+mod player;
+mod synth;
+#[cfg(feature = "wav")]
+mod wav;
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+pub use player::{default_command as default_player, PlayerSink};
+#[cfg(feature = "wav")]
+pub use wav::WavSink;
+
+use crate::assets::LaserKind;
+use crate::events::{EventListener, GameEvent};
+
+/// The number of notes in the invader march heartbeat.
+pub const MARCH_NOTES: u8 = 4;
+
+/// The sound effects played during a game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sound {
+    /// One of the four notes of the invader march, played each time the grid moves.
+    March(u8),
+    Fire,
+    InvaderKilled,
+    /// The drone of the mystery ship, played every tick while it is on screen.
+    MysteryShip,
+    MysteryShipHit,
+    PlayerDeath,
+    BonusLife,
+}
+
+impl Sound {
+    /// Get the sound effect for a game event, if it has one.
+    pub fn for_event(event: &GameEvent) -> Option<Self> {
+        match event {
+            GameEvent::LaserFired {
+                kind: LaserKind::Cannon,
+            } => Some(Self::Fire),
+            GameEvent::GridMoved { note } => Some(Self::March(*note)),
+            GameEvent::MysteryShipMoved => Some(Self::MysteryShip),
+            GameEvent::InvaderKilled { .. } => Some(Self::InvaderKilled),
+            GameEvent::MysteryShipHit { .. } => Some(Self::MysteryShipHit),
+            GameEvent::LifeLost { .. } => Some(Self::PlayerDeath),
+            GameEvent::BonusLife { .. } => Some(Self::BonusLife),
+            _ => None,
+        }
+    }
+}
+
+/// Somewhere to play sound effects.
+pub trait SoundSink {
+    /// Play a sound effect.
+    fn play(&mut self, sound: Sound) -> io::Result<()>;
+
+    /// Advance time by a single tick.
+    fn on_tick(&mut self, _tick_length: Duration) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Finish playing sounds, flushing any output.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A listener that plays the sound effect for each game event through a sink.
+pub struct SoundEffects {
+    sink: Box<dyn SoundSink>,
+}
+
+impl SoundEffects {
+    ///
+    pub fn new(sink: Box<dyn SoundSink>) -> Self {
+        Self { sink }
+    }
+}
+
+impl EventListener for SoundEffects {
+    fn on_event(&mut self, event: &GameEvent) -> io::Result<()> {
+        match Sound::for_event(event) {
+            Some(sound) => self.sink.play(sound),
+            None => Ok(()),
+        }
+    }
+
+    fn is_audible(&self) -> bool {
+        true
+    }
+
+    fn on_tick(&mut self, tick_length: Duration) -> io::Result<()> {
+        self.sink.on_tick(tick_length)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.sink.finish()
+    }
+}
+
+/// A sink that discards all sounds.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullSink;
+
+impl SoundSink for NullSink {
+    fn play(&mut self, _sound: Sound) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A sink that rings the terminal bell for the most important sounds, for when no audio player is
+/// available. The rest are ignored, as the bell can't tell them apart and would ring constantly, so
+/// the march, firing and invaders being destroyed are silent.
+#[derive(Debug)]
+pub struct BellSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> BellSink<W> {
+    ///
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write> SoundSink for BellSink<W> {
+    fn play(&mut self, sound: Sound) -> io::Result<()> {
+        match sound {
+            Sound::MysteryShipHit | Sound::PlayerDeath | Sound::BonusLife => {
+                self.writer.write_all(b"\x07")?;
+                self.writer.flush()
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bell_sink_rings_for_important_sounds() {
+        let mut output = Vec::new();
+        let mut sink = BellSink::new(&mut output);
+
+        sink.play(Sound::March(0)).unwrap();
+        sink.play(Sound::Fire).unwrap();
+        sink.play(Sound::PlayerDeath).unwrap();
+        sink.play(Sound::BonusLife).unwrap();
+
+        assert_eq!(output, b"\x07\x07");
+    }
+}
@@ -0,0 +1,213 @@
+//!
+
+use std::io::{self, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use super::synth::{self, Synth, SAMPLE_RATE};
+use super::{BellSink, Sound, SoundSink};
+
+/// The number of ticks of samples that can wait for the player before later ones are dropped.
+const QUEUE_TICKS: usize = 8;
+/// How long the player is given to play the last sounds once the game has quit.
+const EXIT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A sink that synthesises every sound and streams it to an external audio player, such as
+/// `aplay`, which reads raw 16 bit mono samples from its standard input. If the player exits, the
+/// sink falls back to ringing the bell for the most important sounds.
+#[derive(Debug)]
+pub struct PlayerSink<W: Write> {
+    player: Option<Player>,
+    synth: Synth,
+    bell: BellSink<W>,
+}
+
+impl<W: Write> PlayerSink<W> {
+    /// Start the player, which is given as a program followed by its arguments.
+    pub fn spawn(command: &[String], bell: BellSink<W>) -> io::Result<Self> {
+        let (program, args) = command.split_first().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "the sound player is empty")
+        })?;
+
+        let child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        Ok(Self {
+            player: Some(Player::new(child)),
+            synth: Synth::new(),
+            bell,
+        })
+    }
+}
+
+/// The default command to play sounds with, using ALSA's player.
+pub fn default_command() -> Vec<String> {
+    let rate = SAMPLE_RATE.to_string();
+
+    [
+        "aplay", "-q", "-t", "raw", "-f", "S16_LE", "-c", "1", "-r", &rate,
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect()
+}
+
+impl<W: Write> SoundSink for PlayerSink<W> {
+    fn play(&mut self, sound: Sound) -> io::Result<()> {
+        if self.player.is_some() {
+            self.synth.play(sound);
+            Ok(())
+        } else {
+            self.bell.play(sound)
+        }
+    }
+
+    fn on_tick(&mut self, tick_length: Duration) -> io::Result<()> {
+        let samples = self.synth.advance(tick_length);
+
+        // a player that has gone away, for example because there is no audio device, shouldn't
+        // end the game
+        if let Some(player) = self.player.take() {
+            if player.send(synth::to_bytes(&samples)) {
+                self.player = Some(player);
+            } else {
+                player.stop()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if let Some(player) = self.player.take() {
+            player.send(synth::to_bytes(&self.synth.drain()));
+            player.stop()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A running audio player, whose samples are written by a thread of their own so a player that
+/// stalls, for example because the audio device is busy, can't hold up the game.
+#[derive(Debug)]
+struct Player {
+    child: Child,
+    samples: SyncSender<Vec<u8>>,
+    writer: JoinHandle<()>,
+}
+
+impl Player {
+    fn new(mut child: Child) -> Self {
+        let (samples, queue) = mpsc::sync_channel::<Vec<u8>>(QUEUE_TICKS);
+        let stdin = child.stdin.take();
+
+        let writer = thread::spawn(move || {
+            let Some(mut stdin) = stdin else {
+                return;
+            };
+
+            for bytes in queue {
+                if stdin.write_all(&bytes).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            child,
+            samples,
+            writer,
+        }
+    }
+
+    /// Queue samples to be played, returning whether the player is still running. Samples are
+    /// dropped if the player has fallen too far behind.
+    fn send(&self, bytes: Vec<u8>) -> bool {
+        !matches!(
+            self.samples.try_send(bytes),
+            Err(TrySendError::Disconnected(_))
+        )
+    }
+
+    /// Let the player finish the samples already queued, killing it if it takes too long.
+    fn stop(mut self) -> io::Result<()> {
+        drop(self.samples);
+        let deadline = Instant::now() + EXIT_TIMEOUT;
+
+        while self.child.try_wait()?.is_none() {
+            if Instant::now() >= deadline {
+                // the player may have exited since it was checked
+                let _ = self.child.kill();
+                break;
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        self.child.wait()?;
+
+        // killing the player ends any write the thread was stuck in
+        let _ = self.writer.join();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_player_falls_back_to_bell() {
+        let mut output = Vec::new();
+
+        // `true` exits straight away without reading any samples
+        let command = vec!["true".to_string()];
+        let mut sink = PlayerSink::spawn(&command, BellSink::new(&mut output)).unwrap();
+
+        // the writer thread notices the player has gone when it next writes to it
+        for _ in 0..500 {
+            sink.play(Sound::PlayerDeath).unwrap();
+            sink.on_tick(Duration::from_millis(50)).unwrap();
+
+            if sink.player.is_none() {
+                break;
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        sink.play(Sound::PlayerDeath).unwrap();
+        sink.finish().unwrap();
+        assert!(!output.is_empty());
+    }
+
+    #[test]
+    fn test_stalled_player_doesnt_hold_up_game() {
+        let start = Instant::now();
+
+        // `sleep` never reads its samples, so the pipe to it soon fills up
+        let command = vec!["sleep".to_string(), "60".to_string()];
+        let mut sink = PlayerSink::spawn(&command, BellSink::new(Vec::new())).unwrap();
+
+        for _ in 0..100 {
+            sink.play(Sound::PlayerDeath).unwrap();
+            sink.on_tick(Duration::from_millis(500)).unwrap();
+        }
+
+        sink.finish().unwrap();
+        assert!(start.elapsed() < EXIT_TIMEOUT * 5);
+    }
+
+    #[test]
+    fn test_player_must_be_given() {
+        assert!(PlayerSink::spawn(&[], BellSink::new(Vec::new())).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//!
+
+use std::f64::consts::PI;
+use std::time::Duration;
+
+use super::Sound;
+
+/// The number of samples per second of synthesised sound.
+pub const SAMPLE_RATE: u32 = 22050;
+const AMPLITUDE: f64 = 8000.0;
+
+/// The frequencies of the four notes of the invader march.
+const MARCH_FREQUENCIES: [f64; 4] = [98.0, 87.3, 77.8, 73.4];
+
+/// Synthesises sound effects as 16 bit mono samples. Sounds are mixed onto a timeline that
+/// advances with each tick, and only the samples still to be played are kept, so memory use is
+/// bounded by the longest sound rather than the length of the session.
+#[derive(Debug)]
+pub struct Synth {
+    pending: Vec<i16>,
+    noise: u32,
+}
+
+impl Synth {
+    ///
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+            noise: 1,
+        }
+    }
+
+    /// Mix a sound into the timeline, starting at the current position.
+    pub fn play(&mut self, sound: Sound) {
+        let samples = match sound {
+            Sound::March(note) => {
+                let frequency = MARCH_FREQUENCIES[note as usize % MARCH_FREQUENCIES.len()];
+                tone(frequency, frequency, 0.1)
+            }
+            Sound::Fire => tone(1200.0, 400.0, 0.15),
+            Sound::InvaderKilled => self.noise(0.2),
+            Sound::MysteryShip => tone(440.0, 660.0, 0.05),
+            Sound::MysteryShipHit => tone(1500.0, 300.0, 0.3),
+            Sound::PlayerDeath => self.noise(0.6),
+            Sound::BonusLife => tone(880.0, 880.0, 0.3),
+        };
+
+        if self.pending.len() < samples.len() {
+            self.pending.resize(samples.len(), 0);
+        }
+
+        for (mixed, sample) in self.pending.iter_mut().zip(samples) {
+            *mixed = mixed.saturating_add(sample);
+        }
+    }
+
+    /// Advance the timeline by a tick, returning the samples played during it. Silence fills any
+    /// part of the tick without a sound.
+    pub fn advance(&mut self, tick_length: Duration) -> Vec<i16> {
+        let count = (tick_length.as_secs_f64() * SAMPLE_RATE as f64) as usize;
+        let mut samples: Vec<i16> = self
+            .pending
+            .drain(..count.min(self.pending.len()))
+            .collect();
+        samples.resize(count, 0);
+
+        samples
+    }
+
+    /// Take the rest of the sounds still playing, for when no more ticks will follow.
+    pub fn drain(&mut self) -> Vec<i16> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// White noise that fades out, generated with a linear congruential generator so the output is
+    /// the same on every run.
+    fn noise(&mut self, seconds: f64) -> Vec<i16> {
+        let count = (seconds * SAMPLE_RATE as f64) as usize;
+
+        (0..count)
+            .map(|i| {
+                self.noise = self.noise.wrapping_mul(1103515245).wrapping_add(12345);
+                let value = ((self.noise >> 16) & 0x7fff) as f64 / 0x7fff as f64 * 2.0 - 1.0;
+                let fade = 1.0 - (i as f64 / count as f64);
+
+                (value * AMPLITUDE * fade) as i16
+            })
+            .collect()
+    }
+}
+
+impl Default for Synth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A square wave sweeping from one frequency to another.
+fn tone(from: f64, to: f64, seconds: f64) -> Vec<i16> {
+    let count = (seconds * SAMPLE_RATE as f64) as usize;
+    let mut phase: f64 = 0.0;
+
+    (0..count)
+        .map(|i| {
+            let frequency = from + (to - from) * (i as f64 / count as f64);
+            phase += 2.0 * PI * frequency / SAMPLE_RATE as f64;
+
+            if phase.sin() >= 0.0 {
+                AMPLITUDE as i16
+            } else {
+                -AMPLITUDE as i16
+            }
+        })
+        .collect()
+}
+
+/// Encode samples as little endian bytes, as both WAV files and raw audio players expect.
+pub fn to_bytes(samples: &[i16]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_synth_only_keeps_pending_samples() {
+        let mut synth = Synth::new();
+        let tick = Duration::from_millis(50);
+        let tick_samples = (0.05 * SAMPLE_RATE as f64) as usize;
+
+        synth.play(Sound::PlayerDeath);
+        let first = synth.advance(tick);
+        assert_eq!(first.len(), tick_samples);
+        assert!(first.iter().any(|sample| *sample != 0));
+
+        // long after the sound has finished, ticks are silent and nothing is kept
+        for _ in 0..100 {
+            synth.advance(tick);
+        }
+
+        assert!(synth.advance(tick).iter().all(|sample| *sample == 0));
+        assert!(synth.drain().is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+//!
+
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use super::synth::{self, Synth, SAMPLE_RATE};
+use super::{Sound, SoundSink};
+
+/// The length of the header before the samples in a WAV file.
+const HEADER_LEN: u32 = 44;
+
+/// A sink that synthesises sounds and renders them to a WAV file, for testing sound effects without
+/// an audio device. Samples are streamed to the file as each tick passes, and the lengths in the
+/// header are filled in when the sink is finished.
+#[derive(Debug)]
+pub struct WavSink<W: Write + Seek> {
+    writer: W,
+    synth: Synth,
+    data_len: u32,
+}
+
+impl WavSink<BufWriter<File>> {
+    /// Create the WAV file, replacing any that already exists.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write + Seek> WavSink<W> {
+    ///
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        write_header(&mut writer, 0)?;
+
+        Ok(Self {
+            writer,
+            synth: Synth::new(),
+            data_len: 0,
+        })
+    }
+
+    fn write_samples(&mut self, samples: &[i16]) -> io::Result<()> {
+        self.writer.write_all(&synth::to_bytes(samples))?;
+        self.data_len = self.data_len.saturating_add((samples.len() * 2) as u32);
+
+        Ok(())
+    }
+}
+
+fn write_header<W: Write>(writer: &mut W, data_len: u32) -> io::Result<()> {
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&(HEADER_LEN - 8 + data_len).to_le_bytes())?;
+    writer.write_all(b"WAVE")?;
+
+    writer.write_all(b"fmt ")?;
+    writer.write_all(&16u32.to_le_bytes())?;
+    writer.write_all(&1u16.to_le_bytes())?; // PCM
+    writer.write_all(&1u16.to_le_bytes())?; // mono
+    writer.write_all(&SAMPLE_RATE.to_le_bytes())?;
+    writer.write_all(&(SAMPLE_RATE * 2).to_le_bytes())?;
+    writer.write_all(&2u16.to_le_bytes())?;
+    writer.write_all(&16u16.to_le_bytes())?;
+
+    writer.write_all(b"data")?;
+    writer.write_all(&data_len.to_le_bytes())
+}
+
+impl<W: Write + Seek> SoundSink for WavSink<W> {
+    fn play(&mut self, sound: Sound) -> io::Result<()> {
+        self.synth.play(sound);
+
+        Ok(())
+    }
+
+    fn on_tick(&mut self, tick_length: Duration) -> io::Result<()> {
+        let samples = self.synth.advance(tick_length);
+        self.write_samples(&samples)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let samples = self.synth.drain();
+        self.write_samples(&samples)?;
+
+        self.writer.seek(SeekFrom::Start(0))?;
+        write_header(&mut self.writer, self.data_len)?;
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_wav_sink_renders_timeline() {
+        let mut sink = WavSink::new(Cursor::new(Vec::new())).unwrap();
+
+        sink.play(Sound::March(0)).unwrap();
+        sink.on_tick(Duration::from_millis(500)).unwrap();
+        sink.play(Sound::Fire).unwrap();
+        sink.finish().unwrap();
+
+        let contents = sink.writer.into_inner();
+        let expected_samples = (0.65 * SAMPLE_RATE as f64) as usize;
+
+        assert_eq!(&contents[0..4], b"RIFF");
+        assert_eq!(&contents[8..12], b"WAVE");
+        assert_eq!(contents.len(), HEADER_LEN as usize + expected_samples * 2);
+        assert_eq!(
+            u32::from_le_bytes(contents[40..44].try_into().unwrap()) as usize,
+            expected_samples * 2
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//!
+
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assets::{InvaderType, LaserKind};
+use crate::events::{DeathCause, EventListener, GameEvent};
+use crate::paths;
+
+/// The number of ticks between samples of the score, used to chart the score over a game.
+const SCORE_SAMPLE_TICKS: u32 = 100;
+
+/// The number of recent game scores kept in the lifetime statistics.
+const MAX_RECENT_SCORES: usize = 50;
+
+/// Statistics for a single game, or totals across many games.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameStats {
+    pub score: u32,
+    pub shots_fired: u32,
+    pub hits: u32,
+    pub kills: KillCounts,
+    pub mystery_ships_hit: u32,
+    pub mystery_ship_points: u32,
+    pub lives_lost: LivesLost,
+    pub waves_cleared: u32,
+    pub play_time_ms: u64,
+    #[serde(skip)]
+    pub score_samples: Vec<u32>,
+    #[serde(skip)]
+    ticks: u32,
+}
+
+impl GameStats {
+    /// Advance the play time by a single tick, sampling the score every so often.
+    pub fn on_tick(&mut self, tick_length_ms: u64) {
+        self.play_time_ms += tick_length_ms;
+        self.ticks += 1;
+
+        if self.ticks % SCORE_SAMPLE_TICKS == 0 {
+            self.score_samples.push(self.score);
+        }
+    }
+
+    /// The percentage of shots fired that hit an invader or the mystery ship.
+    pub fn accuracy(&self) -> f64 {
+        if self.shots_fired == 0 {
+            0.0
+        } else {
+            100.0 * self.hits as f64 / self.shots_fired as f64
+        }
+    }
+
+    /// Add the counts from another set of statistics to these.
+    fn add(&mut self, other: &GameStats) {
+        self.score += other.score;
+        self.shots_fired += other.shots_fired;
+        self.hits += other.hits;
+        self.kills.add(&other.kills);
+        self.mystery_ships_hit += other.mystery_ships_hit;
+        self.mystery_ship_points += other.mystery_ship_points;
+        self.lives_lost.add(&other.lives_lost);
+        self.waves_cleared += other.waves_cleared;
+        self.play_time_ms += other.play_time_ms;
+    }
+}
+
+impl EventListener for GameStats {
+    fn on_event(&mut self, event: &GameEvent) -> io::Result<()> {
+        match *event {
+            GameEvent::LaserFired {
+                kind: LaserKind::Cannon,
+            } => self.shots_fired += 1,
+            GameEvent::InvaderKilled {
+                invader_type,
+                score,
+                ..
+            } => {
+                self.hits += 1;
+                self.score += score;
+                self.kills.increment(invader_type);
+            }
+            GameEvent::MysteryShipHit { score } => {
+                self.hits += 1;
+                self.score += score;
+                self.mystery_ships_hit += 1;
+                self.mystery_ship_points += score;
+            }
+            GameEvent::LifeLost { cause } => self.lives_lost.increment(cause),
+            GameEvent::WaveCleared { bonus, .. } => {
+                self.score += bonus;
+                self.waves_cleared += 1;
+            }
+            GameEvent::GameOver { score, .. } => {
+                self.score = score;
+                self.score_samples.push(score);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// The number of invaders of each type that have been killed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KillCounts {
+    pub squid: u32,
+    pub crab: u32,
+    pub octopus: u32,
+}
+
+impl KillCounts {
+    ///
+    pub fn get(&self, invader_type: InvaderType) -> u32 {
+        match invader_type {
+            InvaderType::Squid => self.squid,
+            InvaderType::Crab => self.crab,
+            InvaderType::Octopus => self.octopus,
+        }
+    }
+
+    ///
+    pub fn total(&self) -> u32 {
+        self.squid + self.crab + self.octopus
+    }
+
+    fn increment(&mut self, invader_type: InvaderType) {
+        match invader_type {
+            InvaderType::Squid => self.squid += 1,
+            InvaderType::Crab => self.crab += 1,
+            InvaderType::Octopus => self.octopus += 1,
+        }
+    }
+
+    fn add(&mut self, other: &KillCounts) {
+        self.squid += other.squid;
+        self.crab += other.crab;
+        self.octopus += other.octopus;
+    }
+}
+
+/// The number of lives lost to each cause.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LivesLost {
+    pub rolling: u32,
+    pub plunger: u32,
+    pub squiggly: u32,
+    pub invaded: u32,
+}
+
+impl LivesLost {
+    ///
+    pub fn total(&self) -> u32 {
+        self.rolling + self.plunger + self.squiggly + self.invaded
+    }
+
+    fn increment(&mut self, cause: DeathCause) {
+        match cause {
+            DeathCause::Bomb(LaserKind::Rolling) => self.rolling += 1,
+            DeathCause::Bomb(LaserKind::Plunger) => self.plunger += 1,
+            DeathCause::Bomb(LaserKind::Squiggly) => self.squiggly += 1,
+            DeathCause::Bomb(LaserKind::Cannon) => {}
+            DeathCause::Invaded => self.invaded += 1,
+        }
+    }
+
+    fn add(&mut self, other: &LivesLost) {
+        self.rolling += other.rolling;
+        self.plunger += other.plunger;
+        self.squiggly += other.squiggly;
+        self.invaded += other.invaded;
+    }
+}
+
+/// Statistics across every game played, persisted between runs.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub games_played: u32,
+    pub best_score: u32,
+    pub best_wave: u32,
+    /// The scores of the most recent games, oldest first.
+    pub recent_scores: Vec<u32>,
+    pub totals: GameStats,
+}
+
+impl LifetimeStats {
+    /// Load statistics from a file, falling back to empty statistics if it doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        paths::load_toml(path)
+    }
+
+    /// Write statistics to a file, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        paths::save_toml(path, self)
+    }
+
+    /// Add a finished game to the lifetime statistics.
+    pub fn record(&mut self, game: &GameStats, wave: u32) {
+        self.games_played += 1;
+        self.best_score = self.best_score.max(game.score);
+        self.best_wave = self.best_wave.max(wave);
+        self.totals.add(game);
+
+        self.recent_scores.push(game.score);
+
+        if self.recent_scores.len() > MAX_RECENT_SCORES {
+            self.recent_scores.remove(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_game_stats_from_events() {
+        let mut stats = GameStats::default();
+        let events = [
+            GameEvent::LaserFired {
+                kind: LaserKind::Cannon,
+            },
+            GameEvent::LaserFired {
+                kind: LaserKind::Rolling,
+            },
+            GameEvent::LaserFired {
+                kind: LaserKind::Cannon,
+            },
+            GameEvent::InvaderKilled {
+                invader_type: InvaderType::Squid,
+                position: (0.0, 0.0),
+                score: 30,
+            },
+            GameEvent::LifeLost {
+                cause: DeathCause::Bomb(LaserKind::Plunger),
+            },
+            GameEvent::GameOver { score: 30, wave: 1 },
+        ];
+
+        for event in events.iter() {
+            stats.on_event(event).unwrap();
+        }
+
+        assert_eq!(stats.shots_fired, 2);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.accuracy(), 50.0);
+        assert_eq!(stats.kills.get(InvaderType::Squid), 1);
+        assert_eq!(stats.lives_lost.plunger, 1);
+        assert_eq!(stats.score_samples, vec![30]);
+    }
+
+    #[test]
+    fn test_lifetime_stats_record() {
+        let mut lifetime = LifetimeStats::default();
+        let game = GameStats {
+            score: 500,
+            shots_fired: 10,
+            ..GameStats::default()
+        };
+
+        lifetime.record(&game, 2);
+        lifetime.record(&GameStats::default(), 1);
+
+        assert_eq!(lifetime.games_played, 2);
+        assert_eq!(lifetime.best_score, 500);
+        assert_eq!(lifetime.best_wave, 2);
+        assert_eq!(lifetime.recent_scores, vec![500, 0]);
+        assert_eq!(lifetime.totals.shots_fired, 10);
+
+        let contents = toml::to_string(&lifetime).unwrap();
+        assert_eq!(
+            toml::from_str::<LifetimeStats>(&contents).unwrap(),
+            lifetime
+        );
+    }
+}
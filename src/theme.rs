@@ -0,0 +1,290 @@
+//!
+
+use std::collections::BTreeMap;
+use std::env;
+
+use serde::{Deserialize, Serialize};
+use tui::style::Color;
+
+use crate::assets::InvaderType;
+
+/// The names of the built in themes, in the order they are cycled through on the settings screen.
+pub const BUILT_IN: [&str; 4] = ["classic", "monochrome", "high-contrast", "colorblind"];
+
+/// The colours used to draw the game. Themes defined in the config file start from the classic
+/// theme, so only the colours that differ need to be given.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    #[serde(with = "color")]
+    pub cannon: Color,
+    #[serde(with = "color")]
+    pub bunker: Color,
+    #[serde(with = "color")]
+    pub mystery_ship: Color,
+    #[serde(with = "color")]
+    pub squid: Color,
+    #[serde(with = "color")]
+    pub crab: Color,
+    #[serde(with = "color")]
+    pub octopus: Color,
+    #[serde(with = "color")]
+    pub explosion: Color,
+    #[serde(with = "color")]
+    pub title: Color,
+    #[serde(with = "color")]
+    pub game_over: Color,
+    #[serde(with = "color")]
+    pub text: Color,
+    /// Used to draw attention to things like bonus lives and achievements.
+    #[serde(with = "color")]
+    pub highlight: Color,
+    /// Used for things that are unavailable, like locked achievements.
+    #[serde(with = "color")]
+    pub dim: Color,
+    /// Used for the bars of charts.
+    #[serde(with = "color")]
+    pub chart: Color,
+}
+
+impl Theme {
+    /// The colours of the arcade original, where coloured film over a black and white screen tinted
+    /// the sprites.
+    pub fn classic() -> Self {
+        Self {
+            cannon: Color::Green,
+            bunker: Color::Green,
+            mystery_ship: Color::Red,
+            squid: Color::Yellow,
+            crab: Color::White,
+            octopus: Color::Cyan,
+            explosion: Color::White,
+            title: Color::Yellow,
+            game_over: Color::Red,
+            text: Color::White,
+            highlight: Color::Yellow,
+            dim: Color::DarkGray,
+            chart: Color::Green,
+        }
+    }
+
+    /// No colours at all, leaving everything in the terminal's default colours.
+    pub fn monochrome() -> Self {
+        Self {
+            cannon: Color::Reset,
+            bunker: Color::Reset,
+            mystery_ship: Color::Reset,
+            squid: Color::Reset,
+            crab: Color::Reset,
+            octopus: Color::Reset,
+            explosion: Color::Reset,
+            title: Color::Reset,
+            game_over: Color::Reset,
+            text: Color::Reset,
+            highlight: Color::Reset,
+            dim: Color::Reset,
+            chart: Color::Reset,
+        }
+    }
+
+    /// Bright colours that stand out against a dark background.
+    pub fn high_contrast() -> Self {
+        Self {
+            cannon: Color::LightGreen,
+            bunker: Color::LightGreen,
+            mystery_ship: Color::LightRed,
+            squid: Color::LightYellow,
+            crab: Color::White,
+            octopus: Color::LightCyan,
+            explosion: Color::White,
+            title: Color::LightYellow,
+            game_over: Color::LightRed,
+            text: Color::White,
+            highlight: Color::LightYellow,
+            dim: Color::Gray,
+            chart: Color::LightGreen,
+        }
+    }
+
+    /// Colours from the Okabe-Ito palette, which can be told apart with any kind of colour
+    /// blindness. This avoids pairing red and green, so needs a terminal that supports truecolor.
+    pub fn colorblind() -> Self {
+        Self {
+            cannon: Color::Rgb(0x56, 0xb4, 0xe9),
+            bunker: Color::Rgb(0x00, 0x72, 0xb2),
+            mystery_ship: Color::Rgb(0xe6, 0x9f, 0x00),
+            squid: Color::Rgb(0xf0, 0xe4, 0x42),
+            crab: Color::White,
+            octopus: Color::Rgb(0xcc, 0x79, 0xa7),
+            explosion: Color::White,
+            title: Color::Rgb(0xf0, 0xe4, 0x42),
+            game_over: Color::Rgb(0xd5, 0x5e, 0x00),
+            text: Color::White,
+            highlight: Color::Rgb(0xf0, 0xe4, 0x42),
+            dim: Color::DarkGray,
+            chart: Color::Rgb(0x56, 0xb4, 0xe9),
+        }
+    }
+
+    /// Find a theme by name, preferring those defined in the config file over the built in ones.
+    pub fn find(name: &str, custom: &BTreeMap<String, Theme>) -> Option<Self> {
+        if let Some(theme) = custom.get(name) {
+            return Some(*theme);
+        }
+
+        match name {
+            "classic" => Some(Self::classic()),
+            "monochrome" => Some(Self::monochrome()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "colorblind" => Some(Self::colorblind()),
+            _ => None,
+        }
+    }
+
+    /// The colour to draw a part of the game in.
+    pub fn color(&self, role: Role) -> Color {
+        match role {
+            Role::Cannon => self.cannon,
+            Role::Bunker => self.bunker,
+            Role::MysteryShip => self.mystery_ship,
+            Role::Invader(InvaderType::Squid) => self.squid,
+            Role::Invader(InvaderType::Crab) => self.crab,
+            Role::Invader(InvaderType::Octopus) => self.octopus,
+            Role::Explosion => self.explosion,
+            Role::Title => self.title,
+            Role::GameOver => self.game_over,
+            Role::Text => self.text,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+/// The parts of the game drawn on a canvas, which are coloured by the theme.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// The cannon, its lasers and the remaining lives.
+    Cannon,
+    Bunker,
+    MysteryShip,
+    /// An invader or one of its bombs.
+    Invader(InvaderType),
+    Explosion,
+    Title,
+    GameOver,
+    Text,
+}
+
+/// Check whether colours have been turned off with the `NO_COLOR` environment variable.
+///
+/// See <https://no-color.org>.
+pub fn no_color() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Read and write colours as names like "light-red", hex codes like "#ff8000" or indexes into
+/// the terminal's 256 colour palette like "208".
+mod color {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use tui::style::Color;
+
+    const NAMES: [(&str, Color); 17] = [
+        ("reset", Color::Reset),
+        ("black", Color::Black),
+        ("red", Color::Red),
+        ("green", Color::Green),
+        ("yellow", Color::Yellow),
+        ("blue", Color::Blue),
+        ("magenta", Color::Magenta),
+        ("cyan", Color::Cyan),
+        ("gray", Color::Gray),
+        ("dark-gray", Color::DarkGray),
+        ("light-red", Color::LightRed),
+        ("light-green", Color::LightGreen),
+        ("light-yellow", Color::LightYellow),
+        ("light-blue", Color::LightBlue),
+        ("light-magenta", Color::LightMagenta),
+        ("light-cyan", Color::LightCyan),
+        ("white", Color::White),
+    ];
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        match (NAMES.iter().find(|(_, named)| named == color), color) {
+            (Some((name, _)), _) => serializer.serialize_str(name),
+            (None, Color::Rgb(r, g, b)) => {
+                serializer.serialize_str(&format!("#{r:02x}{g:02x}{b:02x}"))
+            }
+            (None, Color::Indexed(index)) => serializer.serialize_str(&index.to_string()),
+            (None, _) => unreachable!("all other colours are named"),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        parse(&value).ok_or_else(|| {
+            de::Error::custom(format!(
+                "invalid colour \"{value}\", expected a name like \"light-red\" or a hex code like \"#ff8000\""
+            ))
+        })
+    }
+
+    fn parse(value: &str) -> Option<Color> {
+        if let Some((_, color)) = NAMES.iter().find(|(name, _)| *name == value) {
+            return Some(*color);
+        }
+
+        if let Ok(index) = value.parse() {
+            return Some(Color::Indexed(index));
+        }
+
+        let hex = value.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+
+        Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_theme() {
+        let mut custom = BTreeMap::new();
+        custom.insert(
+            "mine".to_string(),
+            toml::from_str::<Theme>("cannon = \"#ff8000\"\nbunker = \"light-blue\"").unwrap(),
+        );
+
+        let theme = Theme::find("mine", &custom).unwrap();
+        assert_eq!(theme.cannon, Color::Rgb(0xff, 0x80, 0x00));
+        assert_eq!(theme.bunker, Color::LightBlue);
+        assert_eq!(theme.crab, Theme::classic().crab);
+
+        for name in BUILT_IN {
+            assert!(Theme::find(name, &custom).is_some());
+        }
+
+        assert_eq!(Theme::find("missing", &custom), None);
+    }
+
+    #[test]
+    fn test_theme_round_trip() {
+        let theme = Theme::colorblind();
+        let contents = toml::to_string(&theme).unwrap();
+
+        assert!(contents.contains("cannon = \"#56b4e9\""));
+        assert!(contents.contains("crab = \"white\""));
+        assert_eq!(toml::from_str::<Theme>(&contents).unwrap(), theme);
+        assert_eq!(
+            toml::from_str::<Theme>("cannon = \"208\"").unwrap().cannon,
+            Color::Indexed(208)
+        );
+        assert!(toml::from_str::<Theme>("cannon = \"#12345\"").is_err());
+    }
+}
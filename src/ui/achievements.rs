@@ -0,0 +1,82 @@
+//!
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    terminal::Frame,
+    widgets::{Block, Borders, Row, Table},
+};
+
+use crate::achievements::Achievement;
+use crate::app::App;
+
+use super::util;
+
+pub fn draw_achievements_screen<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let area = util::app_area(f.size());
+
+    let outer = Block::default()
+        .borders(Borders::ALL)
+        .title(super::APP_TITLE);
+    let inner = outer.inner(area);
+    f.render_widget(outer, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Length(super::ACHIEVEMENTS_HEIGHT),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    draw_achievements_table(f, chunks[1], app);
+    util::draw_centered_text(f, chunks[4], "A Back");
+
+    if let Some(message) = &app.stats_message {
+        util::draw_centered_text(f, chunks[6], message);
+    }
+}
+
+fn draw_achievements_table<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
+    let theme = app.theme();
+    let rows = Achievement::ALL.iter().map(|achievement| {
+        if app.achievements.is_unlocked(*achievement) {
+            Row::new(vec!["✓", achievement.name(), achievement.description()]).style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Row::new(vec![" ", achievement.name(), achievement.description()])
+                .style(Style::default().fg(theme.dim))
+        }
+    });
+
+    let table = Table::new(rows)
+        .widths(&[
+            Constraint::Length(1),
+            Constraint::Length(14),
+            Constraint::Length(40),
+        ])
+        .column_spacing(2)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(super::ACHIEVEMENTS_TITLE),
+        );
+
+    let (constraints, index) = util::center(area.width, super::ACHIEVEMENTS_WIDTH);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(area);
+
+    f.render_widget(table, chunks[index]);
+}
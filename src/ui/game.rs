@@ -3,50 +3,197 @@
 use tui::backend::Backend;
 use tui::layout::Rect;
 use tui::terminal::Frame;
-use tui::widgets::canvas::Canvas;
 use tui::{
     layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph, Row, Table, Tabs},
+    widgets::{Block, Borders, Clear, Paragraph, Row, Table, Tabs},
 };
 
-use crate::app::App;
-use crate::assets;
+use crate::achievements::Achievement;
+use crate::app::{App, GetReady, PauseMenuItem};
+use crate::assets::{self, Themed};
+use crate::points;
+use crate::theme::{Role, Theme};
 
+use super::render::{Renderer, ShapeCanvas};
 use super::util;
 
+/// The parts of the game screen, with the playfield scaled to fit the terminal.
+struct ScreenLayout {
+    /// The whole game screen, which popups are centred within.
+    area: Rect,
+    hud: Hud,
+    game: Rect,
+}
+
+/// Where the score, lives and controls are shown around the playfield.
+enum Hud {
+    /// Bordered rows above and below the playfield.
+    Full {
+        tabs: Rect,
+        score: Rect,
+        lives: Rect,
+    },
+    /// A single line above the playfield, for when space is tight.
+    Compact { status: Rect },
+}
+
+/// Lay out the game screen, using the full HUD if the terminal is big enough for it at the original
+/// size and the compact HUD otherwise. The playfield is scaled to fill the remaining space while
+/// keeping its shape, and is centred horizontally.
+fn screen_layout(size: Rect, zoom: bool) -> ScreenLayout {
+    let compact = size.width < super::APP_WIDTH || size.height < super::APP_HEIGHT;
+    let hud_height = if compact {
+        super::COMPACT_HUD_HEIGHT
+    } else {
+        super::HUD_HEIGHT
+    };
+
+    let scale = playfield_scale(
+        size.width.saturating_sub(2 * super::BORDER_WIDTH),
+        size.height
+            .saturating_sub(hud_height + (2 * super::BORDER_WIDTH)),
+        zoom,
+    );
+    let width = (super::PLAYFIELD_WIDTH as f64 * scale) as u16 + (2 * super::BORDER_WIDTH);
+    let height = (super::PLAYFIELD_HEIGHT as f64 * scale) as u16 + (2 * super::BORDER_WIDTH);
+
+    let area = Rect::new(
+        size.x + (size.width.saturating_sub(width) / 2),
+        size.y,
+        width.min(size.width),
+        (hud_height + height).min(size.height),
+    );
+
+    if compact {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(super::COMPACT_HUD_HEIGHT),
+                Constraint::Length(height),
+            ])
+            .split(area);
+
+        ScreenLayout {
+            area,
+            hud: Hud::Compact { status: chunks[0] },
+            game: chunks[1],
+        }
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(super::ROW_HEIGHT),
+                Constraint::Length(super::ROW_HEIGHT),
+                Constraint::Length(height),
+                Constraint::Length(super::DOUBLE_ROW_HEIGHT),
+            ])
+            .split(area);
+
+        ScreenLayout {
+            area,
+            hud: Hud::Full {
+                tabs: chunks[0],
+                score: chunks[1],
+                lives: chunks[3],
+            },
+            game: chunks[2],
+        }
+    }
+}
+
+/// The largest scale the playfield fits into a space at, in characters. Zooming rounds down to a
+/// whole number so every sprite is drawn with the same number of dots, though playfields that don't
+/// fit at their original size are still shrunk to fit.
+fn playfield_scale(width: u16, height: u16, zoom: bool) -> f64 {
+    let scale = (width as f64 / super::PLAYFIELD_WIDTH as f64)
+        .min(height as f64 / super::PLAYFIELD_HEIGHT as f64);
+
+    if zoom && scale >= 1.0 {
+        scale.floor()
+    } else {
+        scale
+    }
+}
+
+/// The area of the game screen, which popups are centred within.
+pub fn screen_area(size: Rect, zoom: bool) -> Rect {
+    screen_layout(size, zoom).area
+}
+
 pub fn draw_game_screen<B: Backend>(f: &mut Frame<B>, app: &App) {
-    let area = util::app_area(f.size());
+    let layout = screen_layout(f.size(), app.zoom);
+    let area = layout.area;
+    let theme = app.theme();
 
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(super::ROW_HEIGHT),
-            Constraint::Length(super::ROW_HEIGHT),
-            Constraint::Length(super::GAME_HEIGHT),
-            Constraint::Length(super::DOUBLE_ROW_HEIGHT),
-            Constraint::Min(0),
-        ])
-        .split(area);
+    match layout.hud {
+        Hud::Full { tabs, score, lives } => {
+            if let Some(text) = banner_text(app) {
+                draw_banner(f, tabs, text);
+            } else {
+                draw_tabs(f, tabs, app.muted);
+            }
+            draw_score(f, score, app.score, app.wave, app.hiscore);
+            draw_lives(
+                f,
+                lives,
+                app.lives,
+                app.bonus_life_cue > 0,
+                app.renderer(),
+                &theme,
+            );
+        }
+        Hud::Compact { status } => draw_status_line(f, status, app, &theme),
+    }
+
+    draw_game(f, layout.game, app, &theme);
 
-    draw_tabs(f, chunks[0]);
-    draw_score(f, chunks[1], app.score, app.hiscore);
-    draw_game(f, chunks[2], app);
-    draw_lives(f, chunks[3], app.lives);
+    if let Some(achievement) = app.achievement_toasts.first() {
+        draw_achievement_toast(f, layout.game, *achievement, &theme);
+    }
 
     if app.show_help {
         draw_help_popup(f, area);
     }
 
-    if app.paused {
-        draw_paused_popup(f, area);
+    if app.confirm_quit {
+        draw_confirm_quit_popup(f, area, app.confirm_quit_selected);
+    } else if app.paused {
+        draw_pause_menu(f, area, app.pause_selected);
     }
 }
 
-fn draw_tabs<B: Backend>(f: &mut Frame<B>, area: Rect) {
+/// The banner shown in place of the tabs while a game is being watched rather than played.
+fn banner_text(app: &App) -> Option<&'static str> {
+    if app.demo {
+        Some(super::DEMO_TEXT)
+    } else if app.replay.is_some() {
+        Some(super::REPLAY_TEXT)
+    } else {
+        None
+    }
+}
+
+fn draw_banner<B: Backend>(f: &mut Frame<B>, area: Rect, text: &str) {
+    let block = Block::default().borders(Borders::ALL);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    util::draw_centered_text(f, inner, text);
+}
+
+fn draw_tabs<B: Backend>(f: &mut Frame<B>, area: Rect, muted: bool) {
+    let mute_tab_text = if muted {
+        super::UNMUTE_TAB_TEXT
+    } else {
+        super::MUTE_TAB_TEXT
+    };
+
     let titles = [
         super::HELP_TAB_TEXT,
         super::PAUSE_TAB_TEXT,
+        mute_tab_text,
         super::QUIT_TAB_TEXT,
     ]
     .iter()
@@ -63,10 +210,14 @@ fn draw_tabs<B: Backend>(f: &mut Frame<B>, area: Rect) {
     f.render_widget(tabs_widget, area);
 }
 
-fn draw_score<B: Backend>(f: &mut Frame<B>, area: Rect, score: u32, hiscore: u32) {
+fn draw_score<B: Backend>(f: &mut Frame<B>, area: Rect, score: u32, wave: u32, hiscore: u32) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Length(area.width * 2 / 5),
+            Constraint::Length(area.width / 5),
+            Constraint::Min(0),
+        ])
         .split(area);
 
     let score_widget = Paragraph::new(Span::raw(score.to_string())).block(
@@ -75,6 +226,12 @@ fn draw_score<B: Backend>(f: &mut Frame<B>, area: Rect, score: u32, hiscore: u32
             .title(super::SCORE_TITLE),
     );
 
+    let wave_widget = Paragraph::new(Span::raw(wave.to_string())).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(super::WAVE_TITLE),
+    );
+
     let hiscore_widget = Paragraph::new(Span::raw(hiscore.to_string())).block(
         Block::default()
             .borders(Borders::ALL)
@@ -82,54 +239,189 @@ fn draw_score<B: Backend>(f: &mut Frame<B>, area: Rect, score: u32, hiscore: u32
     );
 
     f.render_widget(score_widget, chunks[0]);
-    f.render_widget(hiscore_widget, chunks[1]);
+    f.render_widget(wave_widget, chunks[1]);
+    f.render_widget(hiscore_widget, chunks[2]);
 }
 
-fn draw_game<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
-    let (constraints, index) = util::center(area.width, super::GAME_WIDTH);
+/// Show the score, wave, hiscore and lives on a single line, in place of the full HUD.
+fn draw_status_line<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App, theme: &Theme) {
+    let lives_style = if app.bonus_life_cue > 0 {
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
 
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(constraints)
-        .split(area);
+    let mut spans = Vec::new();
+
+    if let Some(text) = banner_text(app) {
+        spans.push(Span::styled(
+            text,
+            Style::default().add_modifier(Modifier::REVERSED),
+        ));
+        spans.push(Span::raw("  "));
+    }
+
+    spans.push(Span::raw(format!(
+        "{} {}  {} {}  {} {}  ",
+        super::SCORE_TITLE,
+        app.score,
+        super::WAVE_TITLE,
+        app.wave,
+        super::HISCORE_TITLE,
+        app.hiscore,
+    )));
+    spans.push(Span::styled(
+        format!("{} {}", super::LIVES_TITLE, app.lives),
+        lives_style,
+    ));
+
+    f.render_widget(Paragraph::new(Spans::from(spans)), area);
+}
+
+fn draw_game<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App, theme: &Theme) {
+    if let Some(get_ready) = &app.get_ready {
+        draw_get_ready(f, area, app.wave, get_ready, app.renderer(), theme);
+        return;
+    }
 
-    let game = Canvas::default()
+    // the bottom strip of the cellophane overlay covers the bunkers, whatever their shape
+    let cellophane = app.settings.cellophane.then(|| app.bunkers.area().top);
+
+    // the bounds are fixed so the playfield is scaled to fit the area
+    let game = ShapeCanvas::new(app.renderer())
         .block(Block::default().borders(Borders::ALL))
-        .x_bounds([
-            0.0,
-            ((chunks[index].width - (super::BORDER_WIDTH * 2)) * super::HORIZONTAL_DOTS_PER_CHAR)
-                as f64,
-        ])
-        .y_bounds([
-            0.0,
-            ((chunks[index].height - (super::BORDER_WIDTH * 2)) * super::VERTICAL_DOTS_PER_CHAR)
-                as f64,
-        ])
+        .x_bounds([0.0, points::GAME_WIDTH])
+        .y_bounds([0.0, points::GAME_HEIGHT])
         .paint(|ctx| {
-            ctx.draw(&app.cannon);
-            ctx.draw(&app.bunkers);
-            ctx.draw(&app.grid);
-            ctx.draw(&app.mystery_ship);
+            ctx.draw(&Themed::new(&app.ground, theme).cellophane(cellophane));
+            ctx.draw(&Themed::new(&app.cannon, theme).cellophane(cellophane));
+            ctx.draw(&Themed::new(&app.bunkers, theme).cellophane(cellophane));
+            ctx.draw(&Themed::new(&app.grid, theme).cellophane(cellophane));
+            ctx.draw(&Themed::new(&app.mystery_ship, theme).cellophane(cellophane));
 
             for laser in app.cannon_lasers.iter().chain(app.invader_lasers.iter()) {
-                ctx.draw(laser);
+                ctx.draw(&Themed::new(laser, theme).cellophane(cellophane));
+            }
+
+            for explosion in app.explosions.iter() {
+                ctx.draw(&Themed::new(explosion, theme).cellophane(cellophane));
             }
         });
 
-    f.render_widget(game, chunks[index]);
+    f.render_widget(game, area);
 }
 
-fn draw_lives<B: Backend>(f: &mut Frame<B>, area: Rect, lives: u8) {
+/// Show the wave about to start, the bonus for the wave just cleared and a countdown, in place of
+/// the game.
+fn draw_get_ready<B: Backend>(
+    f: &mut Frame<B>,
+    area: Rect,
+    wave: u32,
+    get_ready: &GetReady,
+    renderer: Renderer,
+    theme: &Theme,
+) {
+    let block = Block::default().borders(Borders::ALL);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let wave_words = assets::Words::wave(wave);
+    let countdown_words = assets::Words::number(get_ready.countdown(), Role::Text);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(wave_words.height() as u16 / super::VERTICAL_DOTS_PER_CHAR),
+            Constraint::Length(1),
+            Constraint::Length(super::BONUS_LINES),
+            Constraint::Length(1),
+            Constraint::Length(countdown_words.height() as u16 / super::VERTICAL_DOTS_PER_CHAR),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    util::draw_words(f, chunks[1], wave_words, renderer, theme);
+
+    let lines = match get_ready.bonus {
+        Some(bonus) => vec![
+            format!(
+                "{:<16}{:>6}",
+                format!("Wave {} cleared", wave - 1),
+                bonus.clear
+            ),
+            format!("{:<16}{:>6}", "Spare lives", bonus.lives),
+            format!("{:<16}{:>6}", "Bonus", bonus.total()),
+        ],
+        None => vec![String::new(), "Get ready!".to_string()],
+    };
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); lines.len()])
+        .split(chunks[3]);
+
+    for (line, row) in lines.iter().zip(rows.iter()) {
+        util::draw_centered_text(f, *row, line);
+    }
+
+    util::draw_words(f, chunks[5], countdown_words, renderer, theme);
+}
+
+fn draw_lives<B: Backend>(
+    f: &mut Frame<B>,
+    area: Rect,
+    lives: u8,
+    bonus_life: bool,
+    renderer: Renderer,
+    theme: &Theme,
+) {
     let canvas_width = (area.width - (super::BORDER_WIDTH * 2)) * super::HORIZONTAL_DOTS_PER_CHAR;
     let canvas_height = (area.height - (super::BORDER_WIDTH * 2)) * super::VERTICAL_DOTS_PER_CHAR;
 
-    let lives_widget = Canvas::default()
-        .block(Block::default().borders(Borders::ALL).title("Lives"))
+    // if there are more lives than fit, make room for a label counting the remainder
+    let life_width = points::CANNON_WIDTH + points::LIFE_SPACING_X;
+    let max_icons = ((canvas_width as f64 - points::CANNON_INITIAL_X) / life_width) as u16;
+    let lives = lives as u16;
+    let icons = if lives > max_icons {
+        max_icons.saturating_sub(1)
+    } else {
+        lives
+    };
+
+    let (title, style) = if bonus_life {
+        (
+            super::BONUS_LIFE_TITLE,
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        (super::LIVES_TITLE, Style::default())
+    };
+
+    let lives_widget = ShapeCanvas::new(renderer)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(style)
+                .title(Span::styled(title, style)),
+        )
         .x_bounds([0.0, canvas_width as f64])
         .y_bounds([0.0, canvas_height as f64])
         .paint(|ctx| {
-            for i in 1..=lives {
-                ctx.draw(&assets::Cannon::new_life(i));
+            for i in 1..=icons {
+                ctx.draw(&Themed::new(&assets::Cannon::new_life(i), theme));
+            }
+
+            if icons < lives {
+                ctx.print(
+                    points::CANNON_INITIAL_X + (icons as f64 * life_width),
+                    points::LIFE_INITIAL_Y,
+                    format!("+{}", lives - icons),
+                );
             }
         });
 
@@ -142,7 +434,13 @@ pub fn draw_help_popup<B: Backend>(f: &mut Frame<B>, area: Rect) {
         Row::new(vec!["  ←", "Move cannon left"]),
         Row::new(vec!["  →", "Move cannon right"]),
         Row::new(vec!["SPACE", "Fire cannon"]),
-        Row::new(vec!["  P", "Pause/unpause"]),
+        Row::new(vec!["  P", "Pause menu"]),
+        Row::new(vec!["  M", "Mute/unmute"]),
+        Row::new(vec!["  Z", "Zoom sharp/fit"]),
+        Row::new(vec!["  S", "Operator settings"]),
+        Row::new(vec!["  T", "Statistics"]),
+        Row::new(vec!["  A", "Achievements"]),
+        Row::new(vec!["  R", "Watch replay"]),
         Row::new(vec!["  Q", "Quit"]),
     ])
     .widths(&[Constraint::Length(5), Constraint::Length(17)])
@@ -152,9 +450,125 @@ pub fn draw_help_popup<B: Backend>(f: &mut Frame<B>, area: Rect) {
     util::draw_popup(f, area, popup, super::HELP_WIDTH, super::HELP_HEIGHT);
 }
 
-fn draw_paused_popup<B: Backend>(f: &mut Frame<B>, area: Rect) {
-    let popup = Paragraph::new(Span::raw("Press P to unpause"))
+/// Show an unlocked achievement across the top of the game area.
+fn draw_achievement_toast<B: Backend>(
+    f: &mut Frame<B>,
+    area: Rect,
+    achievement: Achievement,
+    theme: &Theme,
+) {
+    let area = Rect {
+        y: area.y + super::BORDER_WIDTH,
+        height: super::ROW_HEIGHT,
+        ..area
+    };
+
+    let popup = Paragraph::new(Span::styled(
+        format!("{} - {}", achievement.name(), achievement.description()),
+        Style::default().fg(theme.highlight),
+    ))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(super::ACHIEVEMENT_TOAST_TITLE),
+    );
+
+    util::draw_popup(
+        f,
+        area,
+        popup,
+        super::ACHIEVEMENT_TOAST_WIDTH,
+        super::ROW_HEIGHT,
+    );
+}
+
+fn draw_pause_menu<B: Backend>(f: &mut Frame<B>, area: Rect, selected: usize) {
+    let rows = PauseMenuItem::ALL.iter().enumerate().map(|(i, item)| {
+        let row = Row::new(vec![item.label()]);
+
+        if i == selected {
+            row.style(Style::default().add_modifier(Modifier::REVERSED))
+        } else {
+            row
+        }
+    });
+
+    let popup = Table::new(rows)
+        .widths(&[Constraint::Length(
+            super::PAUSE_WIDTH - 2 * super::BORDER_WIDTH,
+        )])
         .block(Block::default().borders(Borders::ALL).title("Paused"));
 
     util::draw_popup(f, area, popup, super::PAUSE_WIDTH, super::PAUSE_HEIGHT);
 }
+
+/// Find the pause menu item at a position on screen.
+pub fn pause_menu_item_at(area: Rect, column: u16, row: u16) -> Option<usize> {
+    let popup = util::popup_area(area, super::PAUSE_WIDTH, super::PAUSE_HEIGHT);
+    let inner = Block::default().borders(Borders::ALL).inner(popup);
+
+    if util::contains(inner, column, row) {
+        Some((row - inner.y) as usize)
+    } else {
+        None
+    }
+}
+
+fn draw_confirm_quit_popup<B: Backend>(f: &mut Frame<B>, area: Rect, yes_selected: bool) {
+    let popup = util::popup_area(area, super::CONFIRM_WIDTH, super::CONFIRM_HEIGHT);
+    let block = Block::default().borders(Borders::ALL).title("Quit");
+    let inner = block.inner(popup);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+    util::draw_centered_text(f, inner, super::CONFIRM_QUIT_TEXT);
+
+    let [yes, no] = confirm_quit_buttons(inner);
+    let selected = Style::default().add_modifier(Modifier::REVERSED);
+
+    for (area, text, is_selected) in [
+        (yes, super::CONFIRM_YES_TEXT, yes_selected),
+        (no, super::CONFIRM_NO_TEXT, !yes_selected),
+    ] {
+        let style = if is_selected {
+            selected
+        } else {
+            Style::default()
+        };
+
+        f.render_widget(Paragraph::new(Span::styled(text, style)), area);
+    }
+}
+
+/// Find the quit confirmation button at a position on screen, returning whether it is "Yes".
+pub fn confirm_quit_button_at(area: Rect, column: u16, row: u16) -> Option<bool> {
+    let popup = util::popup_area(area, super::CONFIRM_WIDTH, super::CONFIRM_HEIGHT);
+    let inner = Block::default().borders(Borders::ALL).inner(popup);
+    let [yes, no] = confirm_quit_buttons(inner);
+
+    if util::contains(yes, column, row) {
+        Some(true)
+    } else if util::contains(no, column, row) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// The areas of the "Yes" and "No" buttons, on the last line of the quit confirmation.
+fn confirm_quit_buttons(inner: Rect) -> [Rect; 2] {
+    let yes_width = super::CONFIRM_YES_TEXT.len() as u16;
+    let no_width = super::CONFIRM_NO_TEXT.len() as u16;
+    let left = inner.x + (inner.width - yes_width - super::CONFIRM_BUTTON_GAP - no_width) / 2;
+    let row = inner.bottom() - 1;
+
+    [
+        Rect::new(left, row, yes_width, 1),
+        Rect::new(
+            left + yes_width + super::CONFIRM_BUTTON_GAP,
+            row,
+            no_width,
+            1,
+        ),
+    ]
+}
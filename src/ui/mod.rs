@@ -1,20 +1,38 @@
 //!
 
+mod achievements;
 mod game;
+mod render;
+mod settings;
 mod start;
+mod stats;
 mod util;
 
-use tui::{backend::Backend, terminal::Frame};
+use tui::{backend::Backend, layout::Rect, terminal::Frame};
 
 use crate::app::App;
 
+pub use render::Renderer;
+
 const APP_TITLE: &str = "Space Invaders";
 const SCORE_TITLE: &str = "Score";
 const HISCORE_TITLE: &str = "Hiscore";
+const WAVE_TITLE: &str = "Wave";
+const SETTINGS_TITLE: &str = "Operator Settings";
+const STATS_TITLE: &str = "Lifetime Statistics";
+const ACHIEVEMENTS_TITLE: &str = "Achievements";
+const ACHIEVEMENT_TOAST_TITLE: &str = "Achievement unlocked";
+const SUMMARY_TITLE: &str = "Summary";
+const LIVES_TITLE: &str = "Lives";
+const BONUS_LIFE_TITLE: &str = "Lives - Bonus life!";
 
 const HELP_TAB_TEXT: &str = "[H]elp";
 const PAUSE_TAB_TEXT: &str = "[P]ause";
+const MUTE_TAB_TEXT: &str = "[M]ute";
+const UNMUTE_TAB_TEXT: &str = "Un[m]ute";
 const QUIT_TAB_TEXT: &str = "[Q]uit";
+const DEMO_TEXT: &str = "DEMO - Press any key";
+const REPLAY_TEXT: &str = "REPLAY - Press any key";
 
 const VERTICAL_DOTS_PER_CHAR: u16 = 4;
 const HORIZONTAL_DOTS_PER_CHAR: u16 = 2;
@@ -22,32 +40,112 @@ const HORIZONTAL_DOTS_PER_CHAR: u16 = 2;
 const BORDER_WIDTH: u16 = 1;
 const ROW_HEIGHT: u16 = 1 + (2 * BORDER_WIDTH);
 const DOUBLE_ROW_HEIGHT: u16 = 2 + (2 * BORDER_WIDTH);
-const GAME_WIDTH: u16 = 100 + (2 * BORDER_WIDTH);
-const GAME_HEIGHT: u16 = 33 + (2 * BORDER_WIDTH);
+const PLAYFIELD_WIDTH: u16 = 100;
+const PLAYFIELD_HEIGHT: u16 = 33;
+const GAME_WIDTH: u16 = PLAYFIELD_WIDTH + (2 * BORDER_WIDTH);
+const GAME_HEIGHT: u16 = PLAYFIELD_HEIGHT + (2 * BORDER_WIDTH);
+
+const HUD_HEIGHT: u16 = (2 * ROW_HEIGHT) + DOUBLE_ROW_HEIGHT;
+const COMPACT_HUD_HEIGHT: u16 = 1;
 
-const APP_HEIGHT: u16 = (2 * ROW_HEIGHT) + DOUBLE_ROW_HEIGHT + GAME_HEIGHT;
+const APP_HEIGHT: u16 = HUD_HEIGHT + GAME_HEIGHT;
 const APP_WIDTH: u16 = GAME_WIDTH;
 
+// the playfield can shrink to half size, below which the sprites become unrecognisable
+const MIN_WIDTH: u16 = (PLAYFIELD_WIDTH / 2) + (2 * BORDER_WIDTH);
+const MIN_HEIGHT: u16 = COMPACT_HUD_HEIGHT + (PLAYFIELD_HEIGHT / 2) + (2 * BORDER_WIDTH);
+
+// the other screens leave out their big letters and shrink their charts when short of room, but
+// still need enough for the statistics table and the game over summary
+const MENU_MIN_WIDTH: u16 = 80;
+const MENU_MIN_HEIGHT: u16 = 30;
+
 const HELP_WIDTH: u16 = 25 + (2 * BORDER_WIDTH);
-const HELP_HEIGHT: u16 = 6 + (2 * BORDER_WIDTH);
+const HELP_HEIGHT: u16 = 12 + (2 * BORDER_WIDTH);
+
+const SETTINGS_WIDTH: u16 = 37 + (2 * BORDER_WIDTH);
+const SETTINGS_HEIGHT: u16 = 13 + (2 * BORDER_WIDTH);
+
+const STATS_WIDTH: u16 = 34 + (2 * BORDER_WIDTH);
+const STATS_HEIGHT: u16 = 16 + (2 * BORDER_WIDTH);
+const SPARKLINE_HEIGHT: u16 = 8 + (2 * BORDER_WIDTH);
+
+const ACHIEVEMENTS_WIDTH: u16 = 59 + (2 * BORDER_WIDTH);
+const ACHIEVEMENTS_HEIGHT: u16 = 4 + (2 * BORDER_WIDTH);
+const ACHIEVEMENT_TOAST_WIDTH: u16 = 56 + (2 * BORDER_WIDTH);
+
+const BONUS_LINES: u16 = 3;
+
+const SCORE_TABLE_TITLE: &str = "*SCORE ADVANCE TABLE*";
+const SCORE_TABLE_WIDTH: u16 = 21;
+const SCORE_TABLE_HEIGHT: u16 = 13;
+const SCORE_TABLE_SPRITE_WIDTH: u16 = 8;
+
+const SUMMARY_WIDTH: u16 = 80 + (2 * BORDER_WIDTH);
+const SUMMARY_HEIGHT: u16 = SUMMARY_TABLE_HEIGHT + SUMMARY_SPARKLINE_HEIGHT + (2 * BORDER_WIDTH);
+const SUMMARY_TABLE_WIDTH: u16 = 30 + (2 * BORDER_WIDTH);
+const SUMMARY_TABLE_HEIGHT: u16 = 8 + (2 * BORDER_WIDTH);
+const SUMMARY_SPARKLINE_HEIGHT: u16 = 5 + (2 * BORDER_WIDTH);
 
 const PAUSE_WIDTH: u16 = 18 + (2 * BORDER_WIDTH);
-const PAUSE_HEIGHT: u16 = 1 + (2 * BORDER_WIDTH);
+const PAUSE_HEIGHT: u16 = 5 + (2 * BORDER_WIDTH);
+
+const CONFIRM_QUIT_TEXT: &str = "Quit the game in progress?";
+const CONFIRM_YES_TEXT: &str = "[ Yes ]";
+const CONFIRM_NO_TEXT: &str = "[ No ]";
+const CONFIRM_BUTTON_GAP: u16 = 4;
+const CONFIRM_WIDTH: u16 = 30 + (2 * BORDER_WIDTH);
+const CONFIRM_HEIGHT: u16 = 3 + (2 * BORDER_WIDTH);
 
 ///
 pub fn draw<B: Backend>(f: &mut Frame<B>, app: &App) {
     let size = f.size();
+    let (min_width, min_height) = min_size(app);
 
-    if size.width < APP_WIDTH || size.height < APP_HEIGHT {
-        util::draw_too_small_message(f, size);
+    if size.width < min_width || size.height < min_height {
+        util::draw_too_small_message(f, size, min_width, min_height);
         return;
     }
 
-    if !app.started {
-        start::draw_start_screen(f);
+    if app.show_settings {
+        settings::draw_settings_screen(f, app);
+    } else if app.show_stats {
+        stats::draw_stats_screen(f, app);
+    } else if app.show_achievements {
+        achievements::draw_achievements_screen(f, app);
+    } else if !app.started {
+        start::draw_start_screen(f, app);
     } else if app.game_over {
-        start::draw_game_over_screen(f);
+        start::draw_game_over_screen(f, app);
     } else {
         game::draw_game_screen(f, app);
     }
 }
+
+/// The smallest terminal the current screen can be drawn in. The other screens don't shrink as far
+/// as the game does.
+fn min_size(app: &App) -> (u16, u16) {
+    let menu = app.show_settings || app.show_stats || app.show_achievements;
+
+    if app.started && !app.game_over && !menu {
+        (MIN_WIDTH, MIN_HEIGHT)
+    } else {
+        (MENU_MIN_WIDTH, MENU_MIN_HEIGHT)
+    }
+}
+
+/// Check whether the terminal is too small to show the game, even with the playfield shrunk.
+pub fn is_too_small(size: Rect) -> bool {
+    size.width < MIN_WIDTH || size.height < MIN_HEIGHT
+}
+
+/// Find the pause menu item at a position on screen, for mouse support.
+pub fn pause_menu_item_at(app: &App, size: Rect, column: u16, row: u16) -> Option<usize> {
+    game::pause_menu_item_at(game::screen_area(size, app.zoom), column, row)
+}
+
+/// Find the quit confirmation button at a position on screen, for mouse support. Returns whether
+/// the button is "Yes".
+pub fn confirm_quit_button_at(app: &App, size: Rect, column: u16, row: u16) -> Option<bool> {
+    game::confirm_quit_button_at(game::screen_area(size, app.zoom), column, row)
+}
@@ -0,0 +1,264 @@
+//!
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use tui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    symbols::{self, Marker},
+    widgets::{
+        canvas::{Canvas, Context},
+        Block, Widget,
+    },
+};
+
+const UPPER_HALF_BLOCK: &str = "▀";
+const LOWER_HALF_BLOCK: &str = "▄";
+
+/// The ways shapes can be drawn in the terminal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Renderer {
+    /// Braille patterns, with 2x4 dots per character
+    #[default]
+    Braille,
+    /// Half blocks, with 1x2 pixels per character
+    HalfBlock,
+    /// Plain ASCII, with 1x2 pixels per character
+    Ascii,
+}
+
+impl Renderer {
+    ///
+    pub const ALL: [Self; 3] = [Self::Braille, Self::HalfBlock, Self::Ascii];
+
+    ///
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Braille => "Braille",
+            Self::HalfBlock => "Half block",
+            Self::Ascii => "ASCII",
+        }
+    }
+
+    /// The symbol for a character with its top and/or bottom pixel painted.
+    fn symbol(&self, top: bool, bottom: bool) -> &'static str {
+        match (self, top, bottom) {
+            (_, false, false) => " ",
+            (Self::Ascii, true, true) => "#",
+            (Self::Ascii, true, false) => "\"",
+            (Self::Ascii, false, true) => ".",
+            (_, true, true) => symbols::block::FULL,
+            (_, true, false) => UPPER_HALF_BLOCK,
+            (_, false, true) => LOWER_HALF_BLOCK,
+        }
+    }
+}
+
+/// A canvas that draws shapes with any of the renderers. Shapes use the same bounds whichever
+/// renderer is used, so only the resolution they are drawn at changes.
+pub struct ShapeCanvas<'a, F>
+where
+    F: Fn(&mut Context),
+{
+    renderer: Renderer,
+    block: Option<Block<'a>>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    painter: Option<F>,
+}
+
+impl<'a, F> ShapeCanvas<'a, F>
+where
+    F: Fn(&mut Context),
+{
+    ///
+    pub fn new(renderer: Renderer) -> Self {
+        Self {
+            renderer,
+            block: None,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+            painter: None,
+        }
+    }
+
+    ///
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    ///
+    pub fn x_bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.x_bounds = bounds;
+        self
+    }
+
+    ///
+    pub fn y_bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.y_bounds = bounds;
+        self
+    }
+
+    ///
+    pub fn paint(mut self, painter: F) -> Self {
+        self.painter = Some(painter);
+        self
+    }
+}
+
+impl<'a, F> Widget for ShapeCanvas<'a, F>
+where
+    F: Fn(&mut Context),
+{
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+
+        let painter = match self.painter {
+            Some(painter) => painter,
+            None => return,
+        };
+
+        // a canvas can't be drawn without any space, which happens on small terminals
+        if area.area() == 0 {
+            return;
+        }
+
+        let canvas = Canvas::default()
+            .x_bounds(self.x_bounds)
+            .y_bounds(self.y_bounds)
+            .paint(painter);
+
+        if self.renderer == Renderer::Braille {
+            canvas.marker(Marker::Braille).render(area, buf);
+            return;
+        }
+
+        // paint a pixel per character into a buffer twice as tall, then combine pairs of rows
+        let pixels_area = Rect::new(0, 0, area.width, area.height * 2);
+        let mut pixels = Buffer::empty(pixels_area);
+        canvas.marker(Marker::Dot).render(pixels_area, &mut pixels);
+
+        for y in 0..area.height {
+            for x in 0..area.width {
+                let top = pixels.get(x, y * 2);
+                let bottom = pixels.get(x, (y * 2) + 1);
+                let cell = buf.get_mut(area.x + x, area.y + y);
+
+                // text printed on the canvas is kept as it is
+                if let Some(label) = [top, bottom].into_iter().find(|cell| is_label(cell)) {
+                    cell.set_symbol(&label.symbol).set_fg(label.fg);
+                    continue;
+                }
+
+                match (is_pixel(top), is_pixel(bottom)) {
+                    (false, false) => {}
+                    (true, true) if top.fg != bottom.fg && self.renderer == Renderer::HalfBlock => {
+                        cell.set_symbol(UPPER_HALF_BLOCK)
+                            .set_fg(top.fg)
+                            .set_bg(bottom.fg);
+                    }
+                    (is_top, is_bottom) => {
+                        let color = if is_top { top.fg } else { bottom.fg };
+                        cell.set_symbol(self.renderer.symbol(is_top, is_bottom))
+                            .set_fg(color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Check whether a pixel was painted, as a dot.
+fn is_pixel(cell: &Cell) -> bool {
+    cell.symbol == symbols::DOT
+}
+
+/// Check whether some text was printed on the canvas, rather than a pixel painted.
+fn is_label(cell: &Cell) -> bool {
+    cell.symbol != " " && !is_pixel(cell)
+}
+
+#[cfg(test)]
+mod test {
+    use tui::style::Color;
+    use tui::widgets::canvas::Points;
+
+    use super::*;
+
+    /// Draw a shape three characters wide and two high, so each pixel is at a whole coordinate
+    /// with the origin in the bottom left.
+    fn render(renderer: Renderer) -> Buffer {
+        let area = Rect::new(0, 0, 3, 2);
+        let mut buf = Buffer::empty(area);
+
+        ShapeCanvas::new(renderer)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 3.0])
+            .paint(|ctx| {
+                // the top row of characters has a top half, a bottom half and a full character
+                ctx.draw(&Points {
+                    coords: &[(0.0, 3.0), (1.0, 2.0), (2.0, 3.0), (2.0, 2.0)],
+                    color: Color::Green,
+                });
+
+                // the bottom row has a character painted in two colours...
+                ctx.draw(&Points {
+                    coords: &[(0.0, 1.0)],
+                    color: Color::Red,
+                });
+                ctx.draw(&Points {
+                    coords: &[(0.0, 0.0), (1.0, 0.0)],
+                    color: Color::Blue,
+                });
+
+                // ...and a label over a pixel
+                ctx.print(1.0, 1.0, "A");
+            })
+            .render(area, &mut buf);
+
+        buf
+    }
+
+    fn symbols(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf.get(x, y).symbol.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_half_block() {
+        let buf = render(Renderer::HalfBlock);
+
+        assert_eq!(symbols(&buf), vec!["▀▄█", "▀A "]);
+        assert_eq!(buf.get(0, 0).fg, Color::Green);
+        assert_eq!(buf.get(1, 0).fg, Color::Green);
+        assert_eq!(buf.get(2, 0).fg, Color::Green);
+
+        // the top pixel takes the foreground and the bottom pixel the background
+        assert_eq!(buf.get(0, 1).fg, Color::Red);
+        assert_eq!(buf.get(0, 1).bg, Color::Blue);
+    }
+
+    #[test]
+    fn test_ascii() {
+        let buf = render(Renderer::Ascii);
+
+        // ASCII has no background to paint the bottom pixel with, so the top pixel's colour wins
+        assert_eq!(symbols(&buf), vec!["\".#", "#A "]);
+        assert_eq!(buf.get(0, 1).fg, Color::Red);
+        assert_eq!(buf.get(0, 1).bg, Color::Reset);
+    }
+}
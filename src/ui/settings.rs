@@ -0,0 +1,79 @@
+//!
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    terminal::Frame,
+    widgets::{Block, Borders, Row, Table},
+};
+
+use crate::app::App;
+use crate::settings::SettingsItem;
+
+use super::util;
+
+pub fn draw_settings_screen<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let area = util::app_area(f.size());
+
+    let outer = Block::default()
+        .borders(Borders::ALL)
+        .title(super::APP_TITLE);
+    let inner = outer.inner(area);
+    f.render_widget(outer, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Length(super::SETTINGS_HEIGHT),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    draw_settings_table(f, chunks[1], app);
+    util::draw_centered_text(f, chunks[3], "Changes apply to the next game");
+    util::draw_centered_text(
+        f,
+        chunks[4],
+        "↑/↓ Select   ←/→ Change   W Write config   S Back",
+    );
+
+    if let Some(message) = &app.settings_message {
+        util::draw_centered_text(f, chunks[6], message);
+    }
+}
+
+fn draw_settings_table<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
+    let rows = SettingsItem::ALL.iter().enumerate().map(|(i, item)| {
+        let row = Row::new(vec![item.label().to_string(), item.value(&app.settings)]);
+
+        if i == app.settings_selected {
+            row.style(Style::default().add_modifier(Modifier::REVERSED))
+        } else {
+            row
+        }
+    });
+
+    let table = Table::new(rows)
+        .widths(&[Constraint::Length(22), Constraint::Length(13)])
+        .column_spacing(2)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(super::SETTINGS_TITLE),
+        );
+
+    let (constraints, index) = util::center(area.width, super::SETTINGS_WIDTH);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(area);
+
+    f.render_widget(table, chunks[index]);
+}
@@ -3,46 +3,114 @@
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     terminal::Frame,
-    text::Span,
-    widgets::{canvas::Canvas, Block, Borders, Paragraph},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph, Row, Table},
 };
 
-use crate::assets;
+use crate::app::App;
+use crate::assets::{self, Invader, InvaderType, MysteryShip, Themed};
+use crate::points;
+use crate::theme::Theme;
 
-use super::{game, util};
+use super::render::{Renderer, ShapeCanvas};
+use super::{game, stats, util};
 
-pub fn draw_start_screen<B: Backend>(f: &mut Frame<B>) {
-    draw_screen(f, assets::Words::space_invaders());
-}
+/// The gap between the score advance table and the help on the start screen.
+const START_SCREEN_GAP: u16 = 6;
 
-pub fn draw_game_over_screen<B: Backend>(f: &mut Frame<B>) {
-    draw_screen(f, assets::Words::game_over());
-}
+/// The number of rows taken by each entry in the score advance table, including a blank row
+/// between them.
+const SCORE_TABLE_ENTRY_ROWS: u16 = 3;
 
-pub fn draw_screen<B: Backend>(f: &mut Frame<B>, words: assets::Words) {
+pub fn draw_start_screen<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let words = assets::Words::space_invaders();
     let area = util::app_area(f.size());
+    let theme = app.theme();
 
     draw_outer(f, area);
 
     let inner = get_inner(area);
+    let table_height = super::HELP_HEIGHT.max(super::SCORE_TABLE_HEIGHT);
+    let words_rows = util::words_rows(&words, inner, 2 + 2 + 1 + 2 + table_height);
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(4),
-            Constraint::Length(words.height() as u16 / super::VERTICAL_DOTS_PER_CHAR),
-            Constraint::Length(4),
+            Constraint::Length(2),
+            Constraint::Length(words_rows),
+            Constraint::Length(2),
             Constraint::Length(1),
-            Constraint::Length(3),
-            Constraint::Length(super::HELP_HEIGHT),
+            Constraint::Length(2),
+            Constraint::Length(table_height),
             Constraint::Min(0),
         ])
         .split(inner);
 
-    draw_words(f, chunks[1], words);
+    if words_rows > 0 {
+        util::draw_words(f, chunks[1], words, app.renderer(), &theme);
+    }
     draw_start_text(f, chunks[3]);
-    game::draw_help_popup(f, chunks[5]);
+
+    let (constraints, index) = util::center(
+        chunks[5].width,
+        super::SCORE_TABLE_WIDTH + START_SCREEN_GAP + super::HELP_WIDTH,
+    );
+    let area = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(chunks[5])[index];
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(super::SCORE_TABLE_WIDTH),
+            Constraint::Length(START_SCREEN_GAP),
+            Constraint::Length(super::HELP_WIDTH),
+        ])
+        .split(area);
+
+    draw_score_table(f, columns[0], app.renderer(), &theme);
+    game::draw_help_popup(f, columns[2]);
+}
+
+pub fn draw_game_over_screen<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let words = if app.invaded {
+        assets::Words::invaded()
+    } else {
+        assets::Words::game_over()
+    };
+    let area = util::app_area(f.size());
+    let theme = app.theme();
+
+    draw_outer(f, area);
+
+    let inner = get_inner(area);
+    let words_rows = util::words_rows(&words, inner, 2 + 2 + super::SUMMARY_HEIGHT + 1 + 1);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(words_rows),
+            Constraint::Length(2),
+            Constraint::Length(super::SUMMARY_HEIGHT),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    if words_rows > 0 {
+        util::draw_words(f, chunks[1], words, app.renderer(), &theme);
+    }
+    draw_summary(f, chunks[3], app, &theme);
+    util::draw_centered_text(
+        f,
+        chunks[5],
+        "SPACE Play again    R Watch replay    T Statistics    ESC Title screen",
+    );
 }
 
 fn draw_outer<B: Backend>(f: &mut Frame<B>, area: Rect) {
@@ -53,46 +121,164 @@ fn draw_outer<B: Backend>(f: &mut Frame<B>, area: Rect) {
 }
 
 fn get_inner(outer: Rect) -> Rect {
+    Block::default().borders(Borders::ALL).inner(outer)
+}
+
+/// Show the points scored for each kind of invader, as in the arcade attract screen.
+fn draw_score_table<B: Backend>(f: &mut Frame<B>, area: Rect, renderer: Renderer, theme: &Theme) {
+    // the table is left out when the terminal is too small to fit it
+    if area.width < super::SCORE_TABLE_WIDTH || area.height < super::SCORE_TABLE_HEIGHT {
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(area);
+
+    util::draw_centered_text(f, chunks[0], super::SCORE_TABLE_TITLE);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(super::BORDER_WIDTH),
-            Constraint::Length(super::APP_HEIGHT - (2 * super::BORDER_WIDTH)),
+            Constraint::Length(super::SCORE_TABLE_SPRITE_WIDTH),
+            Constraint::Length(1),
             Constraint::Min(0),
         ])
-        .split(outer);
+        .split(chunks[1]);
 
-    let area = chunks[1];
-    let (constraints, index) =
-        util::center(area.width, super::GAME_WIDTH - 2 * super::BORDER_WIDTH);
+    let entries = [
+        (None, "= ? MYSTERY"),
+        (Some(InvaderType::Squid), "= 30 POINTS"),
+        (Some(InvaderType::Crab), "= 20 POINTS"),
+        (Some(InvaderType::Octopus), "= 10 POINTS"),
+    ];
 
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(constraints)
-        .split(area);
+    let width = points::MYSTERY_SHIP_WIDTH;
+    let height = (columns[0].height * super::VERTICAL_DOTS_PER_CHAR) as f64;
+    let entry_height = (SCORE_TABLE_ENTRY_ROWS * super::VERTICAL_DOTS_PER_CHAR) as f64;
+
+    let canvas = ShapeCanvas::new(renderer)
+        .block(Block::default())
+        .x_bounds([0.0, width])
+        .y_bounds([0.0, height])
+        .paint(|ctx| {
+            for (i, (invader_type, _)) in entries.iter().enumerate() {
+                let bottom = height - (i as f64 * entry_height) - points::ALIEN_HEIGHT;
+
+                match invader_type {
+                    Some(invader_type) => {
+                        let invader = Invader::new(*invader_type, 0.0, bottom);
+                        let left = (width - invader.width()) / 2.0;
+                        ctx.draw(&Themed::new(
+                            &Invader::new(*invader_type, left, bottom),
+                            theme,
+                        ));
+                    }
+                    None => ctx.draw(&Themed::new(&MysteryShip::new_at(0.0, bottom), theme)),
+                }
+            }
+        });
 
-    chunks[index]
+    f.render_widget(canvas, columns[0]);
+
+    let lines: Vec<Spans> = entries
+        .iter()
+        .flat_map(|(_, text)| {
+            let mut lines = vec![Spans::from(""); SCORE_TABLE_ENTRY_ROWS as usize];
+            lines[0] = Spans::from(*text);
+            lines
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines), columns[2]);
 }
 
-fn draw_words<B: Backend>(f: &mut Frame<B>, area: Rect, words: assets::Words) {
-    let (constraints, index) = util::center(
-        area.width,
-        words.width() as u16 / super::HORIZONTAL_DOTS_PER_CHAR,
-    );
+fn draw_summary<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App, theme: &Theme) {
+    let (constraints, index) = util::center(area.width, super::SUMMARY_WIDTH);
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(constraints)
         .split(area);
+    let area = chunks[index];
 
-    let widget = Canvas::default()
-        .block(Block::default())
-        .x_bounds([0.0, words.width()])
-        .y_bounds([0.0, words.height()])
-        .paint(|ctx| {
-            ctx.draw(&words);
-        });
+    let outer = Block::default()
+        .borders(Borders::ALL)
+        .title(super::SUMMARY_TITLE);
+    let inner = outer.inner(area);
+    f.render_widget(outer, area);
 
-    f.render_widget(widget, chunks[index]);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(super::SUMMARY_TABLE_HEIGHT),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(super::SUMMARY_TABLE_WIDTH),
+            Constraint::Min(0),
+        ])
+        .split(rows[0]);
+
+    draw_summary_table(f, columns[0], app, theme);
+    stats::draw_kills_chart(f, columns[1], &app.stats.kills, theme);
+    stats::draw_scores_sparkline(
+        f,
+        rows[1],
+        "Score over time",
+        &app.stats.score_samples,
+        theme,
+    );
+}
+
+fn draw_summary_table<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App, theme: &Theme) {
+    let stats = &app.stats;
+    let hiscore = if app.new_hiscore {
+        Row::new(vec!["Hiscore".to_string(), "NEW!".to_string()]).style(
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Row::new(vec!["Hiscore".to_string(), app.hiscore.to_string()])
+    };
+
+    let rows = vec![
+        Row::new(vec!["Final score".to_string(), app.score.to_string()]),
+        hiscore,
+        Row::new(vec!["Wave reached".to_string(), app.wave.to_string()]),
+        Row::new(vec![
+            "Accuracy".to_string(),
+            format!("{:.1}%", stats.accuracy()),
+        ]),
+        Row::new(vec![
+            "Shots fired".to_string(),
+            stats.shots_fired.to_string(),
+        ]),
+        Row::new(vec![
+            "Invaders killed".to_string(),
+            stats.kills.total().to_string(),
+        ]),
+        Row::new(vec![
+            "Mystery ships hit".to_string(),
+            stats.mystery_ships_hit.to_string(),
+        ]),
+        Row::new(vec![
+            "Play time".to_string(),
+            util::format_duration(stats.play_time_ms),
+        ]),
+    ];
+
+    let table = Table::new(rows)
+        .widths(&[Constraint::Length(18), Constraint::Length(10)])
+        .column_spacing(2)
+        .block(Block::default().borders(Borders::ALL));
+
+    f.render_widget(table, area);
 }
 
 fn draw_start_text<B: Backend>(f: &mut Frame<B>, area: Rect) {
@@ -0,0 +1,151 @@
+//!
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    terminal::Frame,
+    widgets::{BarChart, Block, Borders, Row, Sparkline, Table},
+};
+
+use crate::app::App;
+use crate::assets::InvaderType;
+use crate::stats::{KillCounts, LifetimeStats};
+use crate::theme::Theme;
+
+use super::util;
+
+pub fn draw_stats_screen<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let area = util::app_area(f.size());
+    let theme = app.theme();
+
+    let outer = Block::default()
+        .borders(Borders::ALL)
+        .title(super::APP_TITLE);
+    let inner = outer.inner(area);
+    f.render_widget(outer, area);
+
+    // the sparkline gives up rows on short terminals, so the table and help always fit
+    let sparkline_height =
+        super::SPARKLINE_HEIGHT.min(inner.height.saturating_sub(2 + super::STATS_HEIGHT + 4));
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(super::STATS_HEIGHT),
+            Constraint::Length(sparkline_height),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(super::STATS_WIDTH), Constraint::Min(0)])
+        .split(chunks[1]);
+
+    draw_lifetime_table(f, columns[0], &app.lifetime);
+    draw_kills_chart(f, columns[1], &app.lifetime.totals.kills, &theme);
+    draw_scores_sparkline(
+        f,
+        chunks[2],
+        "Score per game",
+        &app.lifetime.recent_scores,
+        &theme,
+    );
+    util::draw_centered_text(f, chunks[4], "T Back");
+
+    if let Some(message) = &app.stats_message {
+        util::draw_centered_text(f, chunks[6], message);
+    }
+}
+
+fn draw_lifetime_table<B: Backend>(f: &mut Frame<B>, area: Rect, lifetime: &LifetimeStats) {
+    let totals = &lifetime.totals;
+    let rows = vec![
+        ("Games played", lifetime.games_played.to_string()),
+        ("Best score", lifetime.best_score.to_string()),
+        ("Best wave", lifetime.best_wave.to_string()),
+        ("Play time", util::format_duration(totals.play_time_ms)),
+        ("Shots fired", totals.shots_fired.to_string()),
+        ("Hits", totals.hits.to_string()),
+        ("Accuracy", format!("{:.1}%", totals.accuracy())),
+        ("Invaders killed", totals.kills.total().to_string()),
+        ("Mystery ships hit", totals.mystery_ships_hit.to_string()),
+        (
+            "Mystery ship points",
+            totals.mystery_ship_points.to_string(),
+        ),
+        ("Waves cleared", totals.waves_cleared.to_string()),
+        ("Lives lost", totals.lives_lost.total().to_string()),
+        ("  to rolling bombs", totals.lives_lost.rolling.to_string()),
+        ("  to plunger bombs", totals.lives_lost.plunger.to_string()),
+        (
+            "  to squiggly bombs",
+            totals.lives_lost.squiggly.to_string(),
+        ),
+        ("  to invasion", totals.lives_lost.invaded.to_string()),
+    ];
+
+    let table = Table::new(
+        rows.into_iter()
+            .map(|(label, value)| Row::new(vec![label.to_string(), value])),
+    )
+    .widths(&[Constraint::Length(20), Constraint::Length(12)])
+    .column_spacing(2)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(super::STATS_TITLE),
+    );
+
+    f.render_widget(table, area);
+}
+
+pub fn draw_kills_chart<B: Backend>(
+    f: &mut Frame<B>,
+    area: Rect,
+    kills: &KillCounts,
+    theme: &Theme,
+) {
+    let data = [
+        ("Squid", kills.get(InvaderType::Squid) as u64),
+        ("Crab", kills.get(InvaderType::Crab) as u64),
+        ("Octopus", kills.get(InvaderType::Octopus) as u64),
+    ];
+
+    let chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title("Kills"))
+        .data(&data)
+        .bar_width(9)
+        .bar_gap(4)
+        .bar_style(Style::default().fg(theme.chart))
+        .value_style(
+            Style::default()
+                .fg(theme.chart)
+                .add_modifier(Modifier::REVERSED),
+        );
+
+    f.render_widget(chart, area);
+}
+
+pub fn draw_scores_sparkline<B: Backend>(
+    f: &mut Frame<B>,
+    area: Rect,
+    title: &str,
+    scores: &[u32],
+    theme: &Theme,
+) {
+    let data: Vec<u64> = scores.iter().map(|score| *score as u64).collect();
+
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(&data)
+        .style(Style::default().fg(theme.highlight));
+
+    f.render_widget(sparkline, area);
+}
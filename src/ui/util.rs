@@ -6,9 +6,14 @@ use tui::terminal::Frame;
 use tui::{
     layout::{Constraint, Direction, Layout},
     text::Span,
-    widgets::{Clear, Paragraph, Widget},
+    widgets::{Block, Clear, Paragraph, Widget},
 };
 
+use crate::assets::{self, Themed};
+use crate::theme::Theme;
+
+use super::render::{Renderer, ShapeCanvas};
+
 ///
 pub fn app_area(area: Rect) -> Rect {
     let chunks = Layout::default()
@@ -31,9 +36,11 @@ pub fn app_area(area: Rect) -> Rect {
 
 ///
 pub fn center(outer: u16, inner: u16) -> (Vec<Constraint>, usize) {
+    // layouts don't shrink to fit on their own, so anything too big is cut down to size
+    let inner = inner.min(outer);
     let margin = (outer - inner) / 2;
 
-    if outer < inner || margin == 0 {
+    if margin == 0 {
         (vec![Constraint::Length(inner), Constraint::Min(0)], 0)
     } else {
         (
@@ -55,6 +62,14 @@ pub fn draw_popup<B: Backend, W: Widget>(
     width: u16,
     height: u16,
 ) {
+    let area = popup_area(area, width, height);
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
+
+/// The area of a popup centred within another area.
+pub fn popup_area(area: Rect, width: u16, height: u16) -> Rect {
     let (constraints, index) = center(area.height, height);
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -67,19 +82,88 @@ pub fn draw_popup<B: Backend, W: Widget>(
         .direction(Direction::Horizontal)
         .constraints(constraints)
         .split(area);
-    let area = chunks[index];
 
-    f.render_widget(Clear, area);
-    f.render_widget(widget, area);
+    chunks[index]
+}
+
+/// Check whether a position on screen is within an area.
+pub fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+}
+
+///
+pub fn draw_centered_text<B: Backend>(f: &mut Frame<B>, area: Rect, text: &str) {
+    let (constraints, index) = center(area.width, text.chars().count() as u16);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(area);
+
+    let widget = Paragraph::new(Span::raw(text.to_string()));
+    f.render_widget(widget, chunks[index]);
+}
+
+/// Format a number of milliseconds as hours, minutes and seconds.
+pub fn format_duration(ms: u64) -> String {
+    let seconds = ms / 1000;
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
 }
 
-pub fn draw_too_small_message<B: Backend>(f: &mut Frame<B>, area: Rect) {
+/// The number of rows to draw big letters in at the top of a screen. This is all the rows they need
+/// if the area has room for them alongside `others` rows of everything else, or none at all.
+pub fn words_rows(words: &assets::Words, area: Rect, others: u16) -> u16 {
+    let rows = words.height() as u16 / super::VERTICAL_DOTS_PER_CHAR;
+    let columns = words.width() as u16 / super::HORIZONTAL_DOTS_PER_CHAR;
+
+    if rows + others <= area.height && columns <= area.width {
+        rows
+    } else {
+        0
+    }
+}
+
+///
+pub fn draw_words<B: Backend>(
+    f: &mut Frame<B>,
+    area: Rect,
+    words: assets::Words,
+    renderer: Renderer,
+    theme: &Theme,
+) {
+    let (constraints, index) = center(
+        area.width,
+        words.width() as u16 / super::HORIZONTAL_DOTS_PER_CHAR,
+    );
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(area);
+
+    let widget = ShapeCanvas::new(renderer)
+        .block(Block::default())
+        .x_bounds([0.0, words.width()])
+        .y_bounds([0.0, words.height()])
+        .paint(|ctx| {
+            ctx.draw(&Themed::new(&words, theme));
+        });
+
+    f.render_widget(widget, chunks[index]);
+}
+
+pub fn draw_too_small_message<B: Backend>(
+    f: &mut Frame<B>,
+    area: Rect,
+    min_width: u16,
+    min_height: u16,
+) {
     let widget = Paragraph::new(Span::raw(format!(
         "Terminal must be at least {}x{} characters, currently {}x{} characters",
-        super::APP_WIDTH,
-        super::APP_HEIGHT,
-        area.width,
-        area.height,
+        min_width, min_height, area.width, area.height,
     )));
     f.render_widget(widget, area);
 }